@@ -0,0 +1,50 @@
+//! Times `next_state` on random boards of several sizes and on the Gosper glider gun, as a
+//! baseline for comparing optimizations against, along with the bit-packed engine on the same
+//! boards.
+//!
+//! Run with `cargo bench --bench next_state`.
+
+use std::time::{Duration, Instant};
+
+use game_of_life::{patterns, GameOfLife, PackedLife, SplitMix64};
+
+/// Each board runs for at least this many generations, and for at least `MIN_TIME`.
+const GENERATIONS: u32 = 10;
+const MIN_TIME: Duration = Duration::from_millis(500);
+
+fn time<T>(name: &str, board: T, next_state: impl Fn(&T) -> T) {
+    let mut board = board;
+    let mut generations = 0;
+    let start = Instant::now();
+    while generations < GENERATIONS || start.elapsed() < MIN_TIME {
+        board = next_state(&board);
+        generations += 1;
+    }
+    println!(
+        "{:<32} {:?} per generation",
+        name,
+        start.elapsed() / generations
+    );
+}
+
+/// Times `game` with both the dense and the bit-packed engine.
+fn time_both(name: &str, game: GameOfLife) {
+    let packed = PackedLife::from(&game);
+    time(name, game, GameOfLife::next_state);
+    time(
+        &format!("{} (packed)", name),
+        packed,
+        PackedLife::next_state,
+    );
+}
+
+fn main() {
+    for &size in &[50, 200, 1000] {
+        let game = GameOfLife::random(size, size, 0.3, &mut SplitMix64::new(1));
+        time_both(&format!("{}x{} soup", size, size), game);
+    }
+    let gun = patterns::gosper_glider_gun()
+        .centered_on(50, 50)
+        .expect("the gun fits on a 50x50 board");
+    time_both("50x50 gosper glider gun", gun);
+}
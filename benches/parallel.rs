@@ -0,0 +1,30 @@
+//! Compares `next_state` with `next_state_par` on a large random board.
+//!
+//! Run with `cargo bench --bench parallel`.
+
+use std::time::{Duration, Instant};
+
+use game_of_life::{GameOfLife, SplitMix64};
+
+const GENERATIONS: u32 = 10;
+
+fn time(game: &GameOfLife, step: impl Fn(&GameOfLife) -> GameOfLife) -> Duration {
+    let start = Instant::now();
+    let mut game = game.clone();
+    for _ in 0..GENERATIONS {
+        game = step(&game);
+    }
+    start.elapsed() / GENERATIONS
+}
+
+fn main() {
+    let game = GameOfLife::random(2000, 2000, 0.3, &mut SplitMix64::new(1));
+    let serial = time(&game, GameOfLife::next_state);
+    let parallel = time(&game, GameOfLife::next_state_par);
+    println!("2000x2000 next_state:     {:?} per generation", serial);
+    println!("2000x2000 next_state_par: {:?} per generation", parallel);
+    println!(
+        "speedup: {:.2}x",
+        serial.as_secs_f64() / parallel.as_secs_f64()
+    );
+}
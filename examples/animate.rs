@@ -0,0 +1,15 @@
+//! Watches a glider cross a small board.
+
+use std::time::Duration;
+
+use game_of_life::{patterns, BoundaryMode, GameOfLife};
+
+fn main() {
+    let mut board = GameOfLife::new(vec![vec![false; 24]; 12])
+        .unwrap()
+        .with_boundary(BoundaryMode::Toroidal);
+    board.overlay(&patterns::glider(), 1, 1);
+    board
+        .animate(100, Duration::from_millis(100))
+        .expect("failed to write to the terminal");
+}
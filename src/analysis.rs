@@ -0,0 +1,893 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::cmp::{max, min};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::transform::padded_grid;
+use crate::GameOfLife;
+
+/// Lists `(row, col, new_value)` for every cell that differs between two equally sized grids.
+fn changes(before: &[Vec<bool>], after: &[Vec<bool>]) -> Vec<(usize, usize, bool)> {
+    let mut changes = Vec::new();
+    for (row_num, (row, after_row)) in before.iter().zip(after).enumerate() {
+        for (col_num, (&alive, &alive_after)) in row.iter().zip(after_row).enumerate() {
+            if alive != alive_after {
+                changes.push((row_num, col_num, alive_after));
+            }
+        }
+    }
+    changes
+}
+
+/// The outcome of [`GameOfLife::run_until_stable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StabilityReport {
+    /// The board entered a cycle: generation `first_step` recurs every `period` generations.
+    Stable { first_step: usize, period: usize },
+    /// No generation repeated within the step budget.
+    Unstable,
+}
+
+/// What a board settles into, as classified by [`GameOfLife::detect_cycle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// Every cell is dead from generation `at` onwards.
+    Extinct { at: usize },
+    /// Generation `at` is a still life, containing at least one live cell.
+    StillLife { at: usize },
+    /// From generation `offset` onwards, the board repeats every `period` generations, where
+    /// `period` is at least 2.
+    Oscillator { period: usize, offset: usize },
+    /// No generation repeated within the generation budget.
+    Unresolved,
+}
+
+/// How the cells of a board changed over one generation, as returned by
+/// [`GameOfLife::step_with_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StepStats {
+    /// Dead cells that came alive.
+    pub births: usize,
+    /// Living cells that died.
+    pub deaths: usize,
+    /// Living cells that stayed alive.
+    pub survivors: usize,
+}
+
+/// A summary of a board's live cells, as returned by [`GameOfLife::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BoardStats {
+    /// The number of living cells.
+    pub population: usize,
+    /// The fraction of the board's cells that are alive, or 0 for a board with no cells.
+    pub density: f64,
+    /// The smallest rectangle containing every live cell, as in
+    /// [`GameOfLife::bounding_box`].
+    pub bounding_box: Option<(usize, usize, usize, usize)>,
+}
+
+impl GameOfLife {
+    /// Summarizes this board's live cells, scanning it once.
+    pub fn stats(&self) -> BoardStats {
+        let (rows, cols) = self.dimensions();
+        let mut stats = BoardStats::default();
+        for (row_num, col_num) in self.live_cells() {
+            stats.population += 1;
+            let (top, left, _, right) = stats
+                .bounding_box
+                .unwrap_or((row_num, col_num, row_num, col_num));
+            stats.bounding_box = Some((top, min(left, col_num), row_num, max(right, col_num)));
+        }
+        if rows * cols > 0 {
+            stats.density = stats.population as f64 / (rows * cols) as f64;
+        }
+        stats
+    }
+
+    /// Whether this board is a fixed point, i.e. its next generation is identical to it.
+    pub fn is_still_life(&self) -> bool {
+        self.next_state() == *self
+    }
+
+    /// Whether this board, advanced `n` generations, equals `expected`, which must also have the
+    /// same rule, boundary mode and neighborhood.
+    pub fn matches_after(&self, n: usize, expected: &GameOfLife) -> bool {
+        self.nth_generation(n) == *expected
+    }
+
+    /// The smallest number of generations, up to `max`, after which this board returns to exactly
+    /// its current cells, or `None` if it doesn't within `max` generations.
+    ///
+    /// A still life has period 1. Spaceships never return to the same cells on a bounded board.
+    pub fn period(&self, max: usize) -> Option<usize> {
+        self.clone()
+            .generations()
+            .skip(1)
+            .take(max)
+            .position(|game| game == *self)
+            .map(|steps| steps + 1)
+    }
+
+    /// The average `(row, col)` position of the live cells, or `None` if there are none.
+    pub fn center_of_mass(&self) -> Option<(f64, f64)> {
+        let live_cells = self.live_cells();
+        if live_cells.is_empty() {
+            return None;
+        }
+        let (row_sum, col_sum) = live_cells
+            .iter()
+            .fold((0.0, 0.0), |(row_sum, col_sum), &(row, col)| {
+                (row_sum + row as f64, col_sum + col as f64)
+            });
+        let count = live_cells.len() as f64;
+        Some((row_sum / count, col_sum / count))
+    }
+
+    /// If this board's pattern is a spaceship with period at most `max_period`, how far it moves
+    /// each period, as `(drow, dcol, period)`.
+    ///
+    /// The pattern is trimmed and given room to move, so it needn't be far from the edges of the
+    /// board. Patterns that don't come back to their own shape, or only do so in place like still
+    /// lifes and oscillators, are `None`.
+    pub fn spaceship_velocity(&self, max_period: usize) -> Option<(i64, i64, usize)> {
+        let start = self.trimmed().padded(max_period);
+        let (start_row, start_col, _, _) = start.bounding_box()?;
+        let shape = start.trimmed();
+        let (period, moved) = start
+            .generations()
+            .enumerate()
+            .skip(1)
+            .take(max_period)
+            .find(|(_, game)| game.trimmed() == shape)?;
+        let (row, col, _, _) = moved.bounding_box()?;
+        let displacement = (row as i64 - start_row as i64, col as i64 - start_col as i64);
+        if displacement == (0, 0) {
+            return None;
+        }
+        Some((displacement.0, displacement.1, period))
+    }
+
+    /// The number of living cells in each generation from this one, generation 0, through
+    /// `generations` inclusive.
+    pub fn population_series(&self, generations: usize) -> Vec<usize> {
+        self.clone()
+            .generations()
+            .take(generations + 1)
+            .map(|game| game.population())
+            .collect()
+    }
+
+    /// The first generation, up to `max`, in which no cell is alive, or `None` if the pattern
+    /// survives that long.
+    ///
+    /// A board that is already extinct reports generation 0.
+    pub fn extinction_generation(&self, max: usize) -> Option<usize> {
+        self.clone()
+            .generations()
+            .take(max + 1)
+            .position(|game| game.is_extinct())
+    }
+
+    /// Steps this board up to `max` generations, stopping as soon as any earlier generation
+    /// recurs.
+    ///
+    /// Unlike [`period`](GameOfLife::period), the cycle needn't include the current board, so
+    /// patterns that settle down after a while are reported too.
+    #[cfg(feature = "std")]
+    pub fn run_until_stable(&self, max: usize) -> StabilityReport {
+        let mut seen = HashMap::new();
+        for (step, game) in self.clone().generations().take(max + 1).enumerate() {
+            if let Some(first_step) = seen.insert(game, step) {
+                return StabilityReport::Stable {
+                    first_step,
+                    period: step - first_step,
+                };
+            }
+        }
+        StabilityReport::Unstable
+    }
+
+    /// Steps this board up to `max_generations` generations, as
+    /// [`run_until_stable`](GameOfLife::run_until_stable) does, and classifies the cycle that it
+    /// enters.
+    ///
+    /// Every generation is kept until one recurs, so memory use grows with the time the board
+    /// takes to settle.
+    #[cfg(feature = "std")]
+    pub fn detect_cycle(&self, max_generations: usize) -> Outcome {
+        let mut seen = HashMap::new();
+        let generations = self.clone().generations().take(max_generations + 1);
+        for (step, game) in generations.enumerate() {
+            if let Some(&first_step) = seen.get(&game) {
+                return match step - first_step {
+                    1 if game.is_extinct() => Outcome::Extinct { at: first_step },
+                    1 => Outcome::StillLife { at: first_step },
+                    period => Outcome::Oscillator {
+                        period,
+                        offset: first_step,
+                    },
+                };
+            }
+            seen.insert(game, step);
+        }
+        Outcome::Unresolved
+    }
+
+    /// Computes the following generation along with counts of the births, deaths and survivals
+    /// that produced it, tallied in the same pass.
+    pub fn step_with_stats(&self) -> (GameOfLife, StepStats) {
+        let (births, deaths, survivors) = (Cell::new(0), Cell::new(0), Cell::new(0));
+        let next = self.next_state_with(|currently_alive, living_neighbor_count| {
+            let alive = self
+                .rule()
+                .is_alive_next(currently_alive, living_neighbor_count);
+            let tally = match (currently_alive, alive) {
+                (false, true) => &births,
+                (true, false) => &deaths,
+                (true, true) => &survivors,
+                (false, false) => return alive,
+            };
+            tally.set(tally.get() + 1);
+            alive
+        });
+        let stats = StepStats {
+            births: births.get(),
+            deaths: deaths.get(),
+            survivors: survivors.get(),
+        };
+        (next, stats)
+    }
+
+    /// Computes the following generation along with how many generations each of its cells has
+    /// been continuously alive, given the ages of this board's cells in `prev_ages`.
+    ///
+    /// Newly born cells have age 1, survivors are one older than in `prev_ages`, and dead cells
+    /// have age 0. Ages missing from `prev_ages` count as 0, and the returned grid always has the
+    /// next board's dimensions, which are larger than this board's if it
+    /// [auto-grows](GameOfLife::with_auto_grow).
+    pub fn step_with_ages(&self, prev_ages: &[Vec<u32>]) -> (GameOfLife, Vec<Vec<u32>>) {
+        let grown_by = self.growth();
+        let next = self.next_state();
+        let ages = next
+            .cells()
+            .iter()
+            .enumerate()
+            .map(|(row_num, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(col_num, &alive)| {
+                        if !alive {
+                            return 0;
+                        }
+                        let prev_age = row_num
+                            .checked_sub(grown_by)
+                            .zip(col_num.checked_sub(grown_by))
+                            .filter(|&(row_num, col_num)| self.get(row_num, col_num) == Some(true))
+                            .and_then(|(row_num, col_num)| prev_ages.get(row_num)?.get(col_num))
+                            .unwrap_or(&0);
+                        prev_age.saturating_add(1)
+                    })
+                    .collect()
+            })
+            .collect();
+        (next, ages)
+    }
+
+    /// Lists `(row, col, new_value)` for every cell whose value in `other` differs from this
+    /// board, in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two boards don't have the same dimensions.
+    pub fn diff(&self, other: &GameOfLife) -> Vec<(usize, usize, bool)> {
+        assert_eq!(
+            self.dimensions(),
+            other.dimensions(),
+            "cannot diff boards of different dimensions"
+        );
+        changes(self.cells(), other.cells())
+    }
+
+    /// Advances this board to the following generation in place, as
+    /// [`step_mut`](GameOfLife::step_mut) does, and returns the cells that changed as
+    /// [`diff`](GameOfLife::diff) would, for renderers that only redraw what moved.
+    ///
+    /// The previous generation is left in the step's scratch buffer, so this needs no copy of the
+    /// board. After a step in which an [auto-growing](GameOfLife::with_auto_grow) board grows, the
+    /// changes are positioned on the grown board.
+    pub fn step_mut_diff(&mut self) -> Vec<(usize, usize, bool)> {
+        self.step_mut();
+        changes(&self.scratch.cells, self.cells())
+    }
+
+    /// The number of separate clusters of live cells, where live cells are connected if they are
+    /// orthogonally adjacent, or also diagonally adjacent if `diagonal` is set.
+    ///
+    /// Clusters never join across the edges of the board, whatever its boundary mode.
+    pub fn components(&self, diagonal: bool) -> usize {
+        self.component_cells(diagonal).len()
+    }
+
+    /// The bounding box of each cluster of live cells, connected as in
+    /// [`components`](GameOfLife::components), as inclusive `(top, left, bottom, right)`
+    /// coordinates ordered by their top-left corners.
+    pub fn component_boxes(&self, diagonal: bool) -> Vec<(usize, usize, usize, usize)> {
+        let mut boxes: Vec<_> = self
+            .component_cells(diagonal)
+            .iter()
+            .map(|cells| {
+                cells.iter().fold(
+                    (usize::MAX, usize::MAX, 0, 0),
+                    |(top, left, bottom, right), &(row, col)| {
+                        (
+                            min(top, row),
+                            min(left, col),
+                            max(bottom, row),
+                            max(right, col),
+                        )
+                    },
+                )
+            })
+            .collect();
+        boxes.sort_unstable();
+        boxes
+    }
+
+    /// The cells of each cluster of live cells, found by flood filling from the first live cell
+    /// of each in row-major order.
+    pub(crate) fn component_cells(&self, diagonal: bool) -> Vec<Vec<(usize, usize)>> {
+        self.clusters(1, diagonal)
+    }
+
+    /// The cells of each cluster of live cells, as [`component_cells`](GameOfLife::component_cells)
+    /// finds them but joining cells up to `reach` rows and columns apart.
+    pub(crate) fn clusters(&self, reach: usize, diagonal: bool) -> Vec<Vec<(usize, usize)>> {
+        let (rows, cols) = self.dimensions();
+        let mut visited = vec![vec![false; cols]; rows];
+        let mut components = Vec::new();
+        for (row_num, col_num) in self.live_cells() {
+            if visited[row_num][col_num] {
+                continue;
+            }
+            visited[row_num][col_num] = true;
+            let mut component = Vec::new();
+            let mut pending = vec![(row_num, col_num)];
+            while let Some((row, col)) = pending.pop() {
+                component.push((row, col));
+                let (first_col, last_col) = (col.saturating_sub(reach), min(col + reach, cols - 1));
+                let neighbors = (row.saturating_sub(reach)..=min(row + reach, rows - 1)).flat_map(
+                    |neighbor_row| {
+                        (first_col..=last_col).map(move |neighbor_col| (neighbor_row, neighbor_col))
+                    },
+                );
+                for (neighbor_row, neighbor_col) in neighbors {
+                    let orthogonal = neighbor_row == row || neighbor_col == col;
+                    if (orthogonal || diagonal)
+                        && self.cells()[neighbor_row][neighbor_col]
+                        && !visited[neighbor_row][neighbor_col]
+                    {
+                        visited[neighbor_row][neighbor_col] = true;
+                        pending.push((neighbor_row, neighbor_col));
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// Runs this board for `generations` generations and counts, for each cell, how many times
+    /// it was born or died.
+    ///
+    /// Cells of a still life never change, while each cell a blinker toggles changes every
+    /// generation.
+    ///
+    /// An [auto-growing](GameOfLife::with_auto_grow) board's map grows along with it, so it
+    /// has the dimensions of the last generation.
+    pub fn activity_map(&self, generations: usize) -> Vec<Vec<u32>> {
+        let (rows, cols) = self.dimensions();
+        let mut activity = vec![vec![0; cols]; rows];
+        let mut game = self.clone();
+        for _ in 0..generations {
+            let (_, cols) = game.dimensions();
+            let grown_by = game.grow();
+            if grown_by > 0 {
+                activity = padded_grid(&activity, cols, grown_by, 0);
+            }
+            let changes = game.step_mut_diff();
+            for (row_num, col_num, _) in changes {
+                activity[row_num][col_num] += 1;
+            }
+        }
+        activity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoardStats, Outcome, StabilityReport, StepStats};
+    use crate::{patterns, BoundaryMode, GameOfLife};
+
+    #[test]
+    fn test_still_lifes() {
+        #[rustfmt::skip]
+        let block = GameOfLife::from_lines(&[
+            "    ",
+            " •• ",
+            " •• ",
+            "    ",
+        ]);
+        #[rustfmt::skip]
+        let beehive = GameOfLife::from_lines(&[
+            "      ",
+            "  ••  ",
+            " •  • ",
+            "  ••  ",
+            "      ",
+        ]);
+        #[rustfmt::skip]
+        let tub = GameOfLife::from_lines(&[
+            "     ",
+            "  •  ",
+            " • • ",
+            "  •  ",
+            "     ",
+        ]);
+        assert!(block.is_still_life());
+        assert!(beehive.is_still_life());
+        assert!(tub.is_still_life());
+    }
+
+    #[test]
+    fn test_blinker_is_not_still_life() {
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "     ",
+            "  •  ",
+            "  •  ",
+            "  •  ",
+            "     ",
+        ]);
+        assert!(!blinker.is_still_life());
+    }
+
+    #[test]
+    fn test_period() {
+        #[rustfmt::skip]
+        let block = GameOfLife::from_lines(&[
+            "    ",
+            " •• ",
+            " •• ",
+            "    ",
+        ]);
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "     ",
+            "  •  ",
+            "  •  ",
+            "  •  ",
+            "     ",
+        ]);
+        #[rustfmt::skip]
+        let beacon = GameOfLife::from_lines(&[
+            "      ",
+            " ••   ",
+            " ••   ",
+            "   •• ",
+            "   •• ",
+        ]);
+        assert_eq!(block.period(10), Some(1));
+        assert_eq!(blinker.period(10), Some(2));
+        assert_eq!(beacon.period(10), Some(2));
+        assert_eq!(blinker.period(1), None);
+    }
+
+    #[test]
+    fn test_glider_has_no_period() {
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            " •    ",
+            "  •   ",
+            "•••   ",
+            "      ",
+            "      ",
+            "      ",
+        ]);
+        assert_eq!(glider.period(50), None);
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        #[rustfmt::skip]
+        let block = GameOfLife::from_lines(&[
+            "    ",
+            " •• ",
+            " •• ",
+            "    ",
+        ]);
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "     ",
+            "  •  ",
+            "  •  ",
+            "  •  ",
+            "     ",
+        ]);
+        #[rustfmt::skip]
+        let dying = GameOfLife::from_lines(&[
+            "     ",
+            " •   ",
+            "   • ",
+            "     ",
+        ]);
+        assert_eq!(
+            block.run_until_stable(10),
+            StabilityReport::Stable {
+                first_step: 0,
+                period: 1
+            }
+        );
+        assert_eq!(
+            blinker.run_until_stable(10),
+            StabilityReport::Stable {
+                first_step: 0,
+                period: 2
+            }
+        );
+        assert_eq!(
+            dying.run_until_stable(10),
+            StabilityReport::Stable {
+                first_step: 1,
+                period: 1
+            }
+        );
+        assert_eq!(blinker.run_until_stable(1), StabilityReport::Unstable);
+    }
+
+    #[test]
+    fn test_stats() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            "    ",
+            "  • ",
+            " •  ",
+            "  • ",
+        ]);
+        assert_eq!(
+            game.stats(),
+            BoardStats {
+                population: 3,
+                density: 3.0 / 16.0,
+                bounding_box: Some((1, 1, 3, 2)),
+            }
+        );
+        assert_eq!(game.stats().bounding_box, game.bounding_box());
+        assert_eq!(GameOfLife::dead(2, 3).stats(), BoardStats::default());
+        assert_eq!(GameOfLife::dead(0, 0).stats(), BoardStats::default());
+    }
+
+    #[test]
+    fn test_detect_cycle() {
+        let block = GameOfLife::from_lines(&["    ", " •• ", " •• ", "    "]);
+        assert_eq!(block.detect_cycle(10), Outcome::StillLife { at: 0 });
+
+        #[rustfmt::skip]
+        let corner = GameOfLife::from_lines(&[
+            "    ",
+            " •• ",
+            " •  ",
+            "    ",
+        ]);
+        assert_eq!(corner.detect_cycle(10), Outcome::StillLife { at: 1 });
+
+        let dying = GameOfLife::from_lines(&["•   ", "   •"]);
+        assert_eq!(dying.detect_cycle(10), Outcome::Extinct { at: 1 });
+        assert_eq!(GameOfLife::dead(2, 2).detect_cycle(0), Outcome::Unresolved);
+        assert_eq!(
+            GameOfLife::dead(2, 2).detect_cycle(1),
+            Outcome::Extinct { at: 0 }
+        );
+
+        // the lone cell dies, leaving a blinker
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "•    ",
+            "   • ",
+            "   • ",
+            "   • ",
+            "     ",
+        ]);
+        assert_eq!(
+            blinker.detect_cycle(10),
+            Outcome::Oscillator {
+                period: 2,
+                offset: 1
+            }
+        );
+        assert_eq!(blinker.detect_cycle(2), Outcome::Unresolved);
+    }
+
+    #[test]
+    fn test_step_with_stats() {
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "     ",
+            "  •  ",
+            "  •  ",
+            "  •  ",
+            "     ",
+        ]);
+        let (next, stats) = blinker.step_with_stats();
+        assert_eq!(next, blinker.next_state());
+        assert_eq!(
+            stats,
+            StepStats {
+                births: 2,
+                deaths: 2,
+                survivors: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "   ",
+            "•••",
+            "   ",
+        ]);
+        assert_eq!(blinker.diff(&blinker), vec![]);
+        assert_eq!(
+            blinker.diff(&blinker.next_state()),
+            vec![(0, 1, true), (1, 0, false), (1, 2, false), (2, 1, true)]
+        );
+    }
+
+    #[test]
+    fn test_step_mut_diff() {
+        let mut game = patterns::glider().padded(2);
+        for _ in 0..8 {
+            let expected = game.diff(&game.next_state());
+            let next = game.next_state();
+            assert_eq!(game.step_mut_diff(), expected);
+            assert_eq!(game, next);
+        }
+        let mut block = patterns::block().padded(1);
+        assert_eq!(block.step_mut_diff(), vec![]);
+
+        // a growing board reports changes on its new, larger grid
+        let mut growing = patterns::glider().with_auto_grow(true);
+        let expected = growing.padded(1).diff(&growing.next_state());
+        assert_eq!(growing.step_mut_diff(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "different dimensions")]
+    fn test_diff_rejects_mismatched_dimensions() {
+        GameOfLife::from_lines(&["•"]).diff(&GameOfLife::from_lines(&["••"]));
+    }
+
+    #[test]
+    fn test_step_with_ages() {
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "   ",
+            "•••",
+            "   ",
+        ]);
+        let (next, ages) = blinker.step_with_ages(&[vec![0; 3], vec![1, 4, 1], vec![0; 3]]);
+        assert_eq!(next, blinker.next_state());
+        assert_eq!(ages, vec![vec![0, 1, 0], vec![0, 5, 0], vec![0, 1, 0]]);
+
+        let (_, ages) = next.step_with_ages(&ages);
+        assert_eq!(ages, vec![vec![0; 3], vec![1, 6, 1], vec![0; 3]]);
+    }
+
+    #[test]
+    fn test_step_with_ages_follows_growth() {
+        let growing = patterns::glider().with_auto_grow(true);
+        let roomy = patterns::glider().padded(1);
+        let (mut game, mut ages) = (growing, vec![vec![1; 3]; 3]);
+        let (mut roomy_game, mut roomy_ages) = (roomy, vec![vec![1; 5]; 5]);
+        for _ in 0..4 {
+            let (next, next_ages) = game.step_with_ages(&ages);
+            let (roomy_next, roomy_next_ages) = roomy_game.step_with_ages(&roomy_ages);
+            let inset = (next.dimensions().0 - roomy_next.dimensions().0) / 2;
+            for (row_num, row) in roomy_next_ages.iter().enumerate() {
+                assert_eq!(
+                    next_ages[row_num + inset][inset..inset + row.len()],
+                    row[..]
+                );
+            }
+            (game, ages, roomy_game, roomy_ages) = (next, next_ages, roomy_next, roomy_next_ages);
+        }
+        // ages taken from a board larger than this one are read where they fit, not overrun
+        let (_, ages) = GameOfLife::from_lines(&["•••"])
+            .with_auto_grow(true)
+            .step_with_ages(&vec![vec![7; 9]; 9]);
+        assert_eq!(ages[1], vec![0, 0, 8, 0, 0]);
+    }
+
+    #[test]
+    fn test_step_with_ages_ignores_stale_ages() {
+        let (_, ages) = GameOfLife::from_lines(&["•••"]).step_with_ages(&[vec![3, 9, 9, 9]]);
+        assert_eq!(ages, vec![vec![0, 10, 0]]);
+        let (_, ages) = GameOfLife::from_lines(&["   ", "•••", "   "]).step_with_ages(&[]);
+        assert_eq!(ages, vec![vec![0, 1, 0], vec![0, 1, 0], vec![0, 1, 0]]);
+    }
+
+    #[test]
+    fn test_extinction_generation() {
+        #[rustfmt::skip]
+        let diagonal = GameOfLife::from_lines(&[
+            "•    ",
+            " •   ",
+            "  •  ",
+            "   • ",
+        ]);
+        assert_eq!(
+            GameOfLife::from_lines(&["•"]).extinction_generation(5),
+            Some(1)
+        );
+        assert_eq!(
+            GameOfLife::from_lines(&[" "]).extinction_generation(0),
+            Some(0)
+        );
+        assert_eq!(diagonal.extinction_generation(5), Some(2));
+        assert_eq!(diagonal.extinction_generation(1), None);
+        assert_eq!(
+            GameOfLife::from_lines(&["••", "••"]).extinction_generation(100),
+            None
+        );
+    }
+
+    #[test]
+    fn test_population_series() {
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "     ",
+            "  •  ",
+            "  •  ",
+            "  •  ",
+            "     ",
+        ]);
+        assert_eq!(blinker.population_series(4), vec![3; 5]);
+        assert_eq!(blinker.population_series(0), vec![3]);
+        assert_eq!(
+            GameOfLife::from_lines(&["•• ", "   ", "  •"]).population_series(2),
+            vec![3, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_spaceship_velocity() {
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            " • ",
+            "  •",
+            "•••",
+        ]);
+        #[rustfmt::skip]
+        let lightweight_spaceship = GameOfLife::from_lines(&[
+            " •  •",
+            "•    ",
+            "•   •",
+            "•••• ",
+        ]);
+        assert_eq!(glider.spaceship_velocity(10), Some((1, 1, 4)));
+        assert_eq!(
+            glider.flip_horizontal().spaceship_velocity(10),
+            Some((1, -1, 4))
+        );
+        assert_eq!(
+            lightweight_spaceship.spaceship_velocity(10),
+            Some((0, -2, 4))
+        );
+        assert_eq!(glider.spaceship_velocity(3), None);
+
+        let block = GameOfLife::from_lines(&["••", "••"]);
+        let blinker = GameOfLife::from_lines(&["•••"]);
+        assert_eq!(block.spaceship_velocity(10), None);
+        assert_eq!(blinker.spaceship_velocity(10), None);
+        assert_eq!(GameOfLife::from_lines(&[" "]).spaceship_velocity(10), None);
+    }
+
+    #[test]
+    fn test_center_of_mass() {
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            " •    ",
+            "  •   ",
+            "•••   ",
+            "      ",
+            "      ",
+        ]);
+        assert_eq!(glider.center_of_mass(), Some((1.4, 1.2)));
+        assert_eq!(glider.nth_generation(4).center_of_mass(), Some((2.4, 2.2)));
+        assert_eq!(
+            GameOfLife::from_lines(&["  ", " •"]).center_of_mass(),
+            Some((1.0, 1.0))
+        );
+        assert_eq!(GameOfLife::from_lines(&["  "]).center_of_mass(), None);
+    }
+
+    #[test]
+    fn test_matches_after() {
+        let vertical = GameOfLife::from_lines(&["   ", " • ", " • ", " • ", "   "]);
+        let horizontal = GameOfLife::from_lines(&["   ", "   ", "•••", "   ", "   "]);
+        assert!(vertical.matches_after(0, &vertical));
+        assert!(vertical.matches_after(1, &horizontal));
+        assert!(vertical.matches_after(4, &vertical));
+        assert!(!vertical.matches_after(3, &vertical));
+        let torus = vertical.clone().with_boundary(BoundaryMode::Toroidal);
+        assert!(!vertical.matches_after(2, &torus));
+    }
+
+    #[test]
+    fn test_activity_map() {
+        let block = GameOfLife::from_lines(&["    ", " •• ", " •• ", "    "]);
+        assert_eq!(block.activity_map(10), vec![vec![0; 4]; 4]);
+
+        let blinker = GameOfLife::from_lines(&["   ", "•••", "   "]);
+        assert_eq!(
+            blinker.activity_map(5),
+            vec![vec![0, 5, 0], vec![5, 0, 5], vec![0, 5, 0]]
+        );
+        assert_eq!(blinker.activity_map(0), vec![vec![0; 3]; 3]);
+    }
+
+    #[test]
+    fn test_activity_map_grows_with_board() {
+        let growing = patterns::glider().with_auto_grow(true);
+        let activity = growing.activity_map(3);
+        let last = growing.nth_generation(3);
+        assert_eq!((activity.len(), activity[0].len()), last.dimensions());
+        // the same run on a board that starts big enough to never grow
+        let roomy = patterns::glider().padded(last.dimensions().0 / 2 - 1);
+        assert_eq!(roomy.dimensions(), last.dimensions());
+        assert_eq!(roomy.activity_map(3), activity);
+    }
+
+    #[test]
+    fn test_components() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            " •        ",
+            "  •    •  ",
+            "•••     • ",
+            "      ••• ",
+            "          ",
+            "••       •",
+        ]);
+        assert_eq!(game.components(true), 4);
+        // each glider falls apart into two pieces without diagonal connections
+        assert_eq!(game.components(false), 6);
+        assert_eq!(GameOfLife::from_lines(&["   "; 2]).components(true), 0);
+        assert_eq!(GameOfLife::from_lines(&[]).components(false), 0);
+    }
+
+    #[test]
+    fn test_component_boxes() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            "       • ",
+            " •      •",
+            "  •   •••",
+            "•••      ",
+            "         ",
+            "••       ",
+        ]);
+        assert_eq!(
+            game.component_boxes(true),
+            vec![(0, 6, 2, 8), (1, 0, 3, 2), (5, 0, 5, 1)]
+        );
+        assert_eq!(game.component_boxes(false).len(), game.components(false));
+        assert_eq!(game.component_boxes(false)[0], (0, 7, 0, 7));
+        assert!(GameOfLife::from_lines(&["  "])
+            .component_boxes(true)
+            .is_empty());
+    }
+}
@@ -0,0 +1,80 @@
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::GameOfLife;
+
+/// Clears the terminal and moves the cursor to its top-left corner.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+impl GameOfLife {
+    /// Plays this board in the terminal, printing up to `generations` frames `delay` apart.
+    ///
+    /// The animation ends early once the board stops changing.
+    pub fn animate(&self, generations: usize, delay: Duration) -> io::Result<()> {
+        self.animate_to(&mut io::stdout().lock(), generations, delay)
+    }
+
+    /// Like [`animate`](GameOfLife::animate), but writes the frames to `out`.
+    pub fn animate_to(
+        &self,
+        out: &mut impl Write,
+        generations: usize,
+        delay: Duration,
+    ) -> io::Result<()> {
+        let mut game = self.clone();
+        for frame in 0..generations {
+            if frame > 0 {
+                thread::sleep(delay);
+            }
+            writeln!(out, "{}{}", CLEAR_SCREEN, game)?;
+            out.flush()?;
+            let next = game.next_state();
+            if next == game {
+                break;
+            }
+            game = next;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::CLEAR_SCREEN;
+    use crate::GameOfLife;
+
+    fn frames(game: &GameOfLife, generations: usize) -> Vec<String> {
+        let mut out = Vec::new();
+        game.animate_to(&mut out, generations, Duration::from_millis(0))
+            .unwrap();
+        String::from_utf8(out)
+            .unwrap()
+            .split(CLEAR_SCREEN)
+            .skip(1)
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_animate_prints_each_generation() {
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "   ",
+            "•••",
+            "   ",
+        ]);
+        assert_eq!(
+            frames(&blinker, 3),
+            vec!["   \n•••\n   \n", " • \n • \n • \n", "   \n•••\n   \n"]
+        );
+    }
+
+    #[test]
+    fn test_animate_stops_when_static() {
+        let block = GameOfLife::from_lines(&["••", "••"]);
+        assert_eq!(frames(&block, 10), vec!["••\n••\n"]);
+    }
+}
@@ -0,0 +1,232 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::transform::padded_grid;
+use crate::GameOfLife;
+
+/// A board shared by several placed patterns, which tracks which pattern each live cell
+/// descends from in order to report when patterns run into each other.
+///
+/// A cell descends from every pattern with a live cell in or around it in the previous
+/// generation. When a cell descends from two patterns that had never interacted, they collide,
+/// and from then on they are tracked as one group.
+#[derive(Debug, Clone)]
+pub struct Arena {
+    board: GameOfLife,
+    /// The group each live cell descends from, if it descends from a placed pattern at all.
+    owners: Vec<Vec<Option<usize>>>,
+    /// A union-find forest of pattern ids, where each group is rooted at its lowest id.
+    parents: Vec<usize>,
+    collisions: Vec<Collision>,
+    generation: usize,
+}
+
+/// Two groups of patterns in an [`Arena`] interacting for the first time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Collision {
+    /// The generation in which the patterns first affected the same cell.
+    pub generation: usize,
+    /// The lowest pattern id in each of the two groups, in increasing order.
+    pub ids: (usize, usize),
+}
+
+impl Arena {
+    /// Creates an arena on `board`, whose existing live cells belong to no pattern.
+    pub fn new(board: GameOfLife) -> Arena {
+        let (rows, cols) = board.dimensions();
+        Arena {
+            board,
+            owners: vec![vec![None; cols]; rows],
+            parents: Vec::new(),
+            collisions: Vec::new(),
+            generation: 0,
+        }
+    }
+
+    /// Stamps `pattern` onto the board as [`GameOfLife::overlay`] does, and returns the id that
+    /// collisions refer to it by.
+    ///
+    /// Ids count up from 0 in the order patterns are placed. A pattern placed over another
+    /// pattern's live cells collides with it immediately.
+    pub fn place(&mut self, pattern: &GameOfLife, at_row: usize, at_col: usize) -> usize {
+        let id = self.parents.len();
+        self.parents.push(id);
+        self.board.overlay(pattern, at_row, at_col);
+        for (row, col) in pattern.live_cells() {
+            let (row, col) = (row.saturating_add(at_row), col.saturating_add(at_col));
+            if let Some(owner) = self.owners.get_mut(row).and_then(|row| row.get_mut(col)) {
+                let previous = owner.replace(id);
+                if let Some(previous) = previous {
+                    self.merge(previous, id);
+                }
+            }
+        }
+        id
+    }
+
+    /// Advances the board one generation, noting any collisions.
+    ///
+    /// An [auto-growing](GameOfLife::with_auto_grow) board grows before the step, rather than
+    /// during it, so that the cells it steps and their owners stay lined up.
+    pub fn step(&mut self) {
+        self.generation += 1;
+        let (_, cols) = self.board.dimensions();
+        let grown_by = self.board.grow();
+        if grown_by > 0 {
+            self.owners = padded_grid(&self.owners, cols, grown_by, None);
+        }
+        let next = self.board.next_state();
+        let mut owners = vec![vec![None; next.dimensions().1]; next.dimensions().0];
+        for (row, col) in next.live_cells() {
+            let mut ancestors: Vec<usize> = self
+                .board
+                .neighbor_coords(row, col)
+                .chain([(row, col)])
+                .filter_map(|(row, col)| self.owners[row][col])
+                .collect();
+            ancestors.sort_unstable();
+            ancestors.dedup();
+            let mut ancestors = ancestors.into_iter();
+            if let Some(first) = ancestors.next() {
+                for other in ancestors {
+                    self.merge(first, other);
+                }
+                owners[row][col] = Some(first);
+            }
+        }
+        // groups may have merged since some owners were recorded
+        for owner in owners.iter_mut().flatten().flatten() {
+            *owner = self.find(*owner);
+        }
+        self.board = next;
+        self.owners = owners;
+    }
+
+    /// The shared board.
+    pub fn board(&self) -> &GameOfLife {
+        &self.board
+    }
+
+    /// How many times [`step`](Arena::step) has been called.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Every collision so far, in the order they happened.
+    pub fn collisions(&self) -> &[Collision] {
+        &self.collisions
+    }
+
+    /// The lowest id in the group holding `id`.
+    fn find(&mut self, id: usize) -> usize {
+        let parent = self.parents[id];
+        if parent == id {
+            return id;
+        }
+        let root = self.find(parent);
+        self.parents[id] = root;
+        root
+    }
+
+    /// Joins the groups holding `first` and `second`, recording a collision if they differ.
+    fn merge(&mut self, first: usize, second: usize) {
+        let (first, second) = (self.find(first), self.find(second));
+        if first == second {
+            return;
+        }
+        let (low, high) = if first < second {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        self.parents[high] = low;
+        self.collisions.push(Collision {
+            generation: self.generation,
+            ids: (low, high),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns;
+
+    fn empty_board(size: usize) -> GameOfLife {
+        GameOfLife::from_lines(&vec![" ".repeat(size).as_str(); size])
+    }
+
+    #[test]
+    fn test_glider_hits_block() {
+        let mut arena = Arena::new(empty_board(20));
+        let glider = arena.place(&patterns::glider(), 0, 0);
+        let block = arena.place(&patterns::block(), 8, 8);
+        let bystander = arena.place(&patterns::block(), 16, 1);
+        assert_eq!((glider, block, bystander), (0, 1, 2));
+        for _ in 0..40 {
+            arena.step();
+        }
+        assert_eq!(arena.generation(), 40);
+        assert_eq!(arena.collisions().len(), 1);
+        let collision = arena.collisions()[0];
+        assert_eq!(collision.ids, (glider, block));
+        assert!(collision.generation > 10 && collision.generation < 30);
+
+        // the block alone never changes, so the collision was the glider's doing
+        let mut without_glider = Arena::new(empty_board(20));
+        without_glider.place(&patterns::block(), 8, 8);
+        for _ in 0..40 {
+            without_glider.step();
+        }
+        assert!(without_glider.collisions().is_empty());
+    }
+
+    #[test]
+    fn test_growing_board_keeps_owners() {
+        let mut arena = Arena::new(empty_board(3).with_auto_grow(true));
+        let glider = arena.place(&patterns::glider(), 0, 0);
+        for _ in 0..8 {
+            arena.step();
+        }
+        let (rows, cols) = arena.board().dimensions();
+        assert!(rows > 3 && cols > 3);
+        assert_eq!((arena.owners.len(), arena.owners[0].len()), (rows, cols));
+        assert_eq!(arena.board().population(), 5);
+        for (row, col) in arena.board().live_cells() {
+            assert_eq!(arena.owners[row][col], Some(glider));
+        }
+        assert!(arena.collisions().is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_placements_collide_at_once() {
+        let mut arena = Arena::new(empty_board(6));
+        arena.place(&patterns::block(), 1, 1);
+        arena.place(&patterns::block(), 2, 2);
+        arena.place(&patterns::blinker(), 0, 5);
+        assert_eq!(
+            arena.collisions(),
+            &[Collision {
+                generation: 0,
+                ids: (0, 1)
+            }]
+        );
+        assert_eq!(arena.board().population(), 10);
+    }
+
+    #[test]
+    fn test_groups_collide_once() {
+        let mut arena = Arena::new(empty_board(12));
+        // two blinkers close enough to interfere, and a placement that joins them again
+        arena.place(&patterns::blinker(), 4, 4);
+        arena.place(&patterns::blinker(), 4, 7);
+        arena.step();
+        arena.step();
+        assert_eq!(arena.collisions().len(), 1);
+        assert_eq!(arena.collisions()[0].ids, (0, 1));
+        let (row, col) = arena.board().live_cells()[0];
+        arena.place(&GameOfLife::from_lines(&["•"]), row, col);
+        assert_eq!(arena.collisions().len(), 2);
+        assert_eq!(arena.collisions()[1].ids, (0, 2));
+    }
+}
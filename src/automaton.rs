@@ -0,0 +1,132 @@
+use core::mem;
+
+#[cfg(feature = "std")]
+use crate::SparseLife;
+use crate::{BitBoard8, FixedLife, GameOfLife, HexLife, MultiStateLife, PackedLife};
+
+/// A board that evolves one generation at a time, whatever its grid or cell states.
+///
+/// Each of the crate's boards implements this by way of its own `next_state`, so code that only
+/// steps and iterates can be written once for square, hexagonal and multi-state boards alike.
+pub trait Automaton: Clone {
+    /// Computes the following generation, leaving this board unchanged.
+    fn next_state(&self) -> Self;
+
+    /// Computes the board `n` generations from now; `n == 0` returns a copy of this board.
+    fn nth_state(&self, n: usize) -> Self {
+        let mut board = self.clone();
+        for _ in 0..n {
+            board = board.next_state();
+        }
+        board
+    }
+
+    /// Iterates over this board followed by every subsequent generation.
+    fn evolve(self) -> Evolution<Self> {
+        Evolution { current: self }
+    }
+}
+
+/// An endless iterator over a board and each of its successive generations.
+///
+/// Created by [`Automaton::evolve`].
+#[derive(Debug)]
+pub struct Evolution<A> {
+    current: A,
+}
+
+impl<A: Automaton> Iterator for Evolution<A> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        let next = self.current.next_state();
+        Some(mem::replace(&mut self.current, next))
+    }
+}
+
+impl Automaton for GameOfLife {
+    fn next_state(&self) -> GameOfLife {
+        GameOfLife::next_state(self)
+    }
+
+    /// Steps a single copy of this board in place, as [`GameOfLife::nth_generation`] does.
+    fn nth_state(&self, n: usize) -> GameOfLife {
+        self.nth_generation(n)
+    }
+}
+
+impl Automaton for HexLife {
+    fn next_state(&self) -> HexLife {
+        HexLife::next_state(self)
+    }
+}
+
+impl Automaton for MultiStateLife {
+    fn next_state(&self) -> MultiStateLife {
+        MultiStateLife::next_state(self)
+    }
+}
+
+impl Automaton for PackedLife {
+    fn next_state(&self) -> PackedLife {
+        PackedLife::next_state(self)
+    }
+}
+
+impl Automaton for BitBoard8 {
+    fn next_state(&self) -> BitBoard8 {
+        BitBoard8::next_state(self)
+    }
+}
+
+impl<const R: usize, const C: usize> Automaton for FixedLife<R, C> {
+    fn next_state(&self) -> FixedLife<R, C> {
+        FixedLife::next_state(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Automaton for SparseLife {
+    fn next_state(&self) -> SparseLife {
+        SparseLife::next_state(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{patterns, GenerationsRule};
+    use alloc::vec::Vec;
+
+    /// The period of an oscillator, found the same way for any kind of board.
+    fn period<A: Automaton + PartialEq>(board: &A) -> Option<usize> {
+        board
+            .clone()
+            .evolve()
+            .skip(1)
+            .take(10)
+            .position(|later| later == *board)
+            .map(|index| index + 1)
+    }
+
+    #[test]
+    fn test_boards_share_stepping() {
+        let blinker = patterns::blinker().padded(1);
+        assert_eq!(period(&blinker), Some(2));
+        assert_eq!(period(&PackedLife::from(&blinker)), Some(2));
+        assert_eq!(Automaton::nth_state(&blinker, 3), blinker.nth_generation(3));
+
+        let hex = HexLife::new(
+            GameOfLife::from_lines(&["    ", "    ", " •• ", "    ", "    "]).into(),
+            HexLife::parse_rule("B2/S34H").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(period(&hex), Some(2));
+        assert_eq!(hex.nth_state(2), hex);
+
+        // in brian's brain a lone pair burns out, so it never returns to its first generation
+        let rows: Vec<Vec<u8>> = vec![vec![0, 0, 0, 0], vec![0, 1, 1, 0], vec![0, 0, 0, 0]];
+        let brain = MultiStateLife::new(rows, GenerationsRule::brians_brain()).unwrap();
+        assert_eq!(period(&brain), None);
+    }
+}
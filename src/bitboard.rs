@@ -0,0 +1,167 @@
+use core::convert::TryFrom;
+
+use crate::packed::{add_to_counts, next_bits};
+use crate::{DimensionsError, GameOfLife, Rule};
+
+/// The largest number of rows or columns a [`BitBoard8`] can have.
+const SIDE: usize = 8;
+
+/// The bits of column 0 in every row.
+const FIRST_COL: u64 = 0x0101_0101_0101_0101;
+/// The bits of column 7 in every row.
+const LAST_COL: u64 = FIRST_COL << (SIDE - 1);
+
+/// A board of up to 8x8 cells held in a single `u64`, where bit `row * 8 + col` is the cell at
+/// `row`, `col`.
+///
+/// Each generation is computed for the whole board at once by shifting the word to line up every
+/// cell with each of its neighbors. Cells beyond the edges are always dead and neighbors are always
+/// the Moore neighborhood.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitBoard8 {
+    bits: u64,
+    rows: usize,
+    cols: usize,
+    rule: Rule,
+}
+
+impl BitBoard8 {
+    /// The board's size as `(rows, cols)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// The board's cells, where bit `row * 8 + col` is the cell at `row`, `col`.
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// The rule this board evolves under.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Whether the cell at `row`, `col` is alive, or `None` if it is outside the board.
+    pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+        if row < self.rows && col < self.cols {
+            Some(self.bits >> (row * SIDE + col) & 1 == 1)
+        } else {
+            None
+        }
+    }
+
+    /// The number of living cells.
+    pub fn population(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Computes the following generation, leaving this board unchanged.
+    pub fn next_state(&self) -> BitBoard8 {
+        let bits = self.bits;
+        // each cell's neighbors, shifted onto the cell; shifts across columns must not carry
+        // into the neighboring row
+        let from_west = bits << 1 & !FIRST_COL;
+        let from_east = bits >> 1 & !LAST_COL;
+        let mut counts = [0; 4];
+        for &row in &[from_west, bits, from_east] {
+            add_to_counts(&mut counts, row << SIDE);
+            add_to_counts(&mut counts, row >> SIDE);
+        }
+        add_to_counts(&mut counts, from_west);
+        add_to_counts(&mut counts, from_east);
+        BitBoard8 {
+            bits: next_bits(self.rule, &counts, bits) & self.mask(),
+            ..*self
+        }
+    }
+
+    /// The bits that hold cells of the board.
+    fn mask(&self) -> u64 {
+        let row_mask = (1u64 << self.cols) - 1;
+        (0..self.rows).fold(0, |mask, row| mask | row_mask << (row * SIDE))
+    }
+}
+
+/// Packs the cells and rule of a board at most 8 cells in each direction; the boundary mode and
+/// neighborhood are not carried over.
+impl TryFrom<&GameOfLife> for BitBoard8 {
+    type Error = DimensionsError;
+
+    fn try_from(game: &GameOfLife) -> Result<BitBoard8, DimensionsError> {
+        let (rows, cols) = game.dimensions();
+        if rows > SIDE || cols > SIDE {
+            return Err(DimensionsError {
+                found: (rows, cols),
+                expected: (SIDE, SIDE),
+            });
+        }
+        let bits = game
+            .live_cells()
+            .iter()
+            .fold(0, |bits, &(row, col)| bits | 1 << (row * SIDE + col));
+        Ok(BitBoard8 {
+            bits,
+            rows,
+            cols,
+            rule: game.rule(),
+        })
+    }
+}
+
+impl From<&BitBoard8> for GameOfLife {
+    fn from(board: &BitBoard8) -> GameOfLife {
+        GameOfLife::from_cells(
+            (0..board.rows)
+                .map(|row| {
+                    (0..board.cols)
+                        .map(|col| board.get(row, col) == Some(true))
+                        .collect()
+                })
+                .collect(),
+        )
+        .with_rule(board.rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{patterns, SplitMix64};
+
+    #[test]
+    fn test_matches_game_of_life() {
+        let mut rng = SplitMix64::new(8);
+        for &(rows, cols) in &[(8, 8), (5, 7), (8, 1), (1, 8), (3, 3), (0, 0)] {
+            for rule in &["B3/S23", "B36/S23", "B2/S", "B0/S8"] {
+                let rule = Rule::parse(rule).unwrap();
+                let game = GameOfLife::random(rows, cols, 0.4, &mut rng).with_rule(rule);
+                let board = BitBoard8::try_from(&game).unwrap();
+                assert_eq!(GameOfLife::from(&board), game);
+                assert_eq!(GameOfLife::from(&board.next_state()), game.next_state());
+            }
+        }
+    }
+
+    #[test]
+    fn test_glider_crosses_board() {
+        let mut game = GameOfLife::from_lines(&["        "; 8]);
+        game.overlay(&patterns::glider(), 0, 0);
+        let mut board = BitBoard8::try_from(&game).unwrap();
+        for _ in 0..20 {
+            board = board.next_state();
+        }
+        assert_eq!(GameOfLife::from(&board), game.nth_generation(20));
+    }
+
+    #[test]
+    fn test_try_from_rejects_large_boards() {
+        let game = GameOfLife::from_lines(&["         "]);
+        assert_eq!(
+            BitBoard8::try_from(&game),
+            Err(DimensionsError {
+                found: (1, 9),
+                expected: (8, 8),
+            })
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use core::convert::TryFrom;
+
+/// How neighbor counting treats cells beyond the edges of the board.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BoundaryMode {
+    /// Everything beyond the edges is permanently dead.
+    #[default]
+    Dead,
+    /// Opposite edges are joined, so the board wraps around like a torus.
+    Toroidal,
+    /// Each edge acts as a mirror, so the cells just past it are reflections of the cells just
+    /// inside it: row or column `-1` reads as `0`, `-2` as `1`, and `len` as `len - 1`.
+    ///
+    /// Each axis is reflected separately, so past a corner the reflection is diagonal, and the
+    /// neighbor at `(-1, -1)` of the corner cell `(0, 0)` is that cell itself. Cells along an
+    /// edge likewise count themselves, or their neighbors along the edge, twice.
+    Mirror,
+    /// The top and bottom edges are joined as on a torus, but the left and right edges are
+    /// joined with a half twist, so whatever leaves one side comes back on the other upside down:
+    /// the neighbor to the right of `(row, cols - 1)` is `(rows - 1 - row, 0)`.
+    KleinBottle,
+}
+
+impl BoundaryMode {
+    /// Maps the cell `offset` away from `cell` onto a board of `dimensions`, all given as
+    /// `(row, col)`, or `None` if it falls off the board.
+    pub(crate) fn resolve(
+        self,
+        cell: (usize, usize),
+        offset: (isize, isize),
+        dimensions: (usize, usize),
+    ) -> Option<(usize, usize)> {
+        let (rows, cols) = (dimensions.0 as isize, dimensions.1 as isize);
+        let row = cell.0 as isize + offset.0;
+        let col = cell.1 as isize + offset.1;
+        match self {
+            BoundaryMode::KleinBottle => {
+                // every crossing of a side edge turns the board upside down
+                let row = if col.div_euclid(cols) % 2 == 0 {
+                    row
+                } else {
+                    -row - 1
+                };
+                Some((row.rem_euclid(rows) as usize, col.rem_euclid(cols) as usize))
+            }
+            _ => Some((self.resolve_axis(row, rows)?, self.resolve_axis(col, cols)?)),
+        }
+    }
+
+    /// Maps `index` onto an axis of length `len`, for the modes that treat each axis separately.
+    fn resolve_axis(self, index: isize, len: isize) -> Option<usize> {
+        let resolved = match self {
+            BoundaryMode::Dead | BoundaryMode::KleinBottle => index,
+            BoundaryMode::Toroidal => index.rem_euclid(len),
+            BoundaryMode::Mirror => {
+                if index < 0 {
+                    -index - 1
+                } else if index >= len {
+                    2 * len - 1 - index
+                } else {
+                    index
+                }
+            }
+        };
+        usize::try_from(resolved).ok().filter(|&i| i < len as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameOfLife;
+
+    #[test]
+    fn test_toroidal_glider_returns_to_start() {
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            " •    ",
+            "  •   ",
+            "•••   ",
+            "      ",
+            "      ",
+            "      ",
+        ])
+        .with_boundary(BoundaryMode::Toroidal);
+        // a glider moves one cell diagonally every 4 generations
+        assert_ne!(glider.nth_generation(12), glider);
+        assert_eq!(glider.nth_generation(24), glider);
+    }
+
+    #[test]
+    fn test_dead_boundary_clips_glider() {
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            " •   ",
+            "  •  ",
+            "•••  ",
+            "     ",
+            "     ",
+        ]);
+        assert_eq!(glider.boundary(), BoundaryMode::Dead);
+        assert_ne!(glider.nth_generation(20), glider);
+    }
+
+    #[test]
+    fn test_mirror_reflects_across_edges() {
+        let resolve = |index, offset| {
+            BoundaryMode::Mirror
+                .resolve((index, 0), (offset, 0), (5, 1))
+                .map(|(row, _)| row)
+        };
+        assert_eq!(resolve(0, -1), Some(0));
+        assert_eq!(resolve(1, -3), Some(1));
+        assert_eq!(resolve(4, 1), Some(4));
+        assert_eq!(resolve(2, 1), Some(3));
+
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            "•  ",
+            "   ",
+            "  •",
+        ])
+        .with_boundary(BoundaryMode::Mirror);
+        // the corner cell reflects onto itself three times over
+        assert_eq!(game.count_living_neighbors(0, 0), 3);
+        assert_eq!(game.count_living_neighbors(0, 1), 2);
+        assert_eq!(game.count_living_neighbors(2, 2), 3);
+        assert_eq!(game.count_living_neighbors(1, 1), 2);
+    }
+
+    #[test]
+    fn test_mirror_keeps_a_block_on_the_edge_stable() {
+        // a domino against a mirror sees its reflection, which together form a block
+        #[rustfmt::skip]
+        let domino = GameOfLife::from_lines(&[
+            "    ",
+            " •• ",
+        ])
+        .with_boundary(BoundaryMode::Mirror);
+        assert!(domino.is_still_life());
+        assert!(!domino.with_boundary(BoundaryMode::Dead).is_still_life());
+    }
+
+    #[test]
+    fn test_klein_bottle_flips_across_side_edges() {
+        let klein = BoundaryMode::KleinBottle;
+        assert_eq!(klein.resolve((1, 3), (0, 1), (5, 4)), Some((3, 0)));
+        assert_eq!(klein.resolve((0, 0), (-1, -1), (5, 4)), Some((0, 3)));
+        assert_eq!(klein.resolve((4, 2), (1, 0), (5, 4)), Some((0, 2)));
+        assert_eq!(klein.resolve((1, 1), (0, 8), (5, 4)), Some((1, 1)));
+
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            " •    ",
+            "  •   ",
+            "•••   ",
+            "      ",
+            "      ",
+            "      ",
+        ])
+        .with_boundary(klein);
+        // after crossing the side edge once, the glider comes back upside down
+        assert_eq!(glider.nth_generation(24), glider.flip_vertical());
+        assert_eq!(glider.nth_generation(48), glider);
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::vec::Vec;
+
+use crate::{GameOfLife, OutOfBounds, Rng};
+
+/// Builds a board from a list of live cells and stamped patterns, checking that they all fit
+/// only once the board is built.
+#[derive(Debug, Clone, Default)]
+pub struct GameOfLifeBuilder {
+    rows: usize,
+    cols: usize,
+    live: Vec<(usize, usize)>,
+}
+
+impl GameOfLifeBuilder {
+    /// Starts building a board with no cells, 0x0 until given
+    /// [`with_dimensions`](GameOfLifeBuilder::with_dimensions).
+    pub fn new() -> GameOfLifeBuilder {
+        GameOfLifeBuilder::default()
+    }
+
+    /// Sets the size of the board to build.
+    pub fn with_dimensions(self, rows: usize, cols: usize) -> GameOfLifeBuilder {
+        GameOfLifeBuilder { rows, cols, ..self }
+    }
+
+    /// Makes the cell at `row`, `col` alive.
+    pub fn set(mut self, row: usize, col: usize) -> GameOfLifeBuilder {
+        self.live.push((row, col));
+        self
+    }
+
+    /// Makes each of `pattern`'s live cells alive, with the pattern's top-left corner at `at`,
+    /// given as `(row, col)`.
+    ///
+    /// Only the pattern's live cells need to fit on the board; its dead cells are ignored.
+    pub fn place(mut self, pattern: &GameOfLife, at: (usize, usize)) -> GameOfLifeBuilder {
+        let (at_row, at_col) = at;
+        self.live.extend(
+            pattern
+                .live_cells()
+                .into_iter()
+                .map(|(row, col)| (at_row.saturating_add(row), at_col.saturating_add(col))),
+        );
+        self
+    }
+
+    /// Fills the `size` rectangle whose top-left corner is at `at`, both given as `(row, col)`,
+    /// with a random soup, where each cell is alive with probability `density` as in
+    /// [`GameOfLife::random`].
+    ///
+    /// Cells outside the rectangle are left as they are, so a soup can be confined to the middle
+    /// of an otherwise empty board.
+    pub fn soup(
+        self,
+        at: (usize, usize),
+        size: (usize, usize),
+        density: f64,
+        rng: &mut impl Rng,
+    ) -> GameOfLifeBuilder {
+        let (rows, cols) = size;
+        self.place(&GameOfLife::random(rows, cols, density, rng), at)
+    }
+
+    /// Builds the board, or gives the first live cell, in the order they were added, that lies
+    /// outside it.
+    pub fn build(&self) -> Result<GameOfLife, OutOfBounds> {
+        GameOfLife::from_coords(self.rows, self.cols, &self.live)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{patterns, SplitMix64};
+
+    #[test]
+    fn test_build() {
+        let game = GameOfLifeBuilder::new()
+            .with_dimensions(4, 5)
+            .set(0, 0)
+            .place(&patterns::block(), (2, 3))
+            .set(0, 0)
+            .build();
+        #[rustfmt::skip]
+        let expected = GameOfLife::from_lines(&[
+            "•    ",
+            "     ",
+            "   ••",
+            "   ••",
+        ]);
+        assert_eq!(game, Ok(expected));
+        assert_eq!(
+            GameOfLifeBuilder::new().build(),
+            Ok(GameOfLife::from_lines(&[]))
+        );
+    }
+
+    #[test]
+    fn test_soup_stays_in_its_rectangle() {
+        let game = GameOfLifeBuilder::new()
+            .with_dimensions(40, 60)
+            .soup((10, 20), (16, 16), 0.5, &mut SplitMix64::new(266))
+            .build()
+            .unwrap();
+        let (top, left, bottom, right) = game.bounding_box().unwrap();
+        assert!(top >= 10 && left >= 20 && bottom < 26 && right < 36);
+        assert_eq!(
+            game.subgrid(10, 20, 16, 16),
+            GameOfLife::random(16, 16, 0.5, &mut SplitMix64::new(266))
+        );
+        assert!(GameOfLifeBuilder::new()
+            .with_dimensions(10, 10)
+            .soup((5, 5), (6, 6), 1.0, &mut SplitMix64::new(1))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_build_rejects_cells_off_the_board() {
+        let builder = GameOfLifeBuilder::new().with_dimensions(3, 3);
+        assert_eq!(
+            builder.clone().set(1, 3).build(),
+            Err(OutOfBounds { row: 1, col: 3 })
+        );
+        // the glider's dead corners may hang off the board, but not its live cells
+        assert!(builder
+            .clone()
+            .place(&patterns::glider(), (0, 0))
+            .build()
+            .is_ok());
+        assert_eq!(
+            builder.clone().place(&patterns::glider(), (1, 0)).build(),
+            Err(OutOfBounds { row: 3, col: 0 })
+        );
+        assert_eq!(
+            builder.place(&patterns::block(), (usize::MAX, 0)).build(),
+            Err(OutOfBounds {
+                row: usize::MAX,
+                col: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::{patterns, GameOfLife};
+
+/// The objects on a board, as counted by [`GameOfLife::census`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Census {
+    /// How many of each recognized object there are, by the names
+    /// [`classify`](GameOfLife::classify) gives them.
+    pub counts: BTreeMap<&'static str, usize>,
+    /// The [canonical](GameOfLife::canonical) form of every object that wasn't recognized, in
+    /// row-major order of their first cells.
+    pub unidentified: Vec<GameOfLife>,
+}
+
+impl Census {
+    /// The number of objects named `name`.
+    pub fn count(&self, name: &str) -> usize {
+        self.counts.get(name).copied().unwrap_or(0)
+    }
+}
+
+impl GameOfLife {
+    /// Splits this board into separate objects and counts how many of each well-known pattern
+    /// there are, in any phase, rotation or reflection, as after running a soup until it settles.
+    ///
+    /// Live cells within two rows and columns of each other belong to the same object, which
+    /// keeps together the parts of oscillators such as the beacon and the pulsar that come apart
+    /// in some phases; it also means objects that close to each other are one unidentified
+    /// object. Patterns are recognized as by [`classify`](GameOfLife::classify).
+    pub fn census(&self) -> Census {
+        let catalog = patterns::catalog();
+        let mut census = Census::default();
+        for cells in self.clusters(2, true) {
+            let object = extract(&cells);
+            let canonical = object.canonical();
+            match catalog.iter().find(|(phase, _)| *phase == canonical.cells) {
+                Some(&(_, name)) => *census.counts.entry(name).or_insert(0) += 1,
+                None => census.unidentified.push(canonical),
+            }
+        }
+        census
+    }
+}
+
+/// A board just large enough to hold `cells`, with only them alive.
+fn extract(cells: &[(usize, usize)]) -> GameOfLife {
+    let top = cells.iter().map(|&(row, _)| row).min().unwrap_or(0);
+    let left = cells.iter().map(|&(_, col)| col).min().unwrap_or(0);
+    let bottom = cells.iter().map(|&(row, _)| row + 1).max().unwrap_or(0);
+    let right = cells.iter().map(|&(_, col)| col + 1).max().unwrap_or(0);
+    let mut object = GameOfLife::dead(bottom - top, right - left);
+    for &(row, col) in cells {
+        object.cells[row - top][col - left] = true;
+    }
+    object
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_census_counts_objects() {
+        let mut board = GameOfLife::dead(30, 40);
+        board.overlay(&patterns::block(), 1, 1);
+        board.overlay(&patterns::block().padded(1), 25, 30);
+        board.overlay(&patterns::blinker(), 2, 10);
+        board.overlay(&patterns::blinker().padded(1).next_state(), 12, 1);
+        board.overlay(&patterns::loaf().flip_vertical(), 10, 20);
+        board.overlay(&patterns::beehive().rotate_cw(), 20, 5);
+        board.overlay(&patterns::glider(), 2, 30);
+        // the beacon's halves only touch diagonally in this phase, and the pulsar's quarters not
+        // at all, but each is still one object
+        board.overlay(&patterns::beacon().padded(1).next_state(), 20, 15);
+        board.overlay(&patterns::r_pentomino(), 14, 32);
+
+        let census = board.census();
+        assert_eq!(census.count("block"), 2);
+        assert_eq!(census.count("blinker"), 2);
+        assert_eq!(census.count("loaf"), 1);
+        assert_eq!(census.count("beehive"), 1);
+        assert_eq!(census.count("glider"), 1);
+        assert_eq!(census.count("beacon"), 1);
+        assert_eq!(census.count("tub"), 0);
+        assert_eq!(census.counts.len(), 6);
+        assert_eq!(
+            census.unidentified,
+            vec![patterns::r_pentomino().canonical()]
+        );
+
+        let pulsar = patterns::pulsar().padded(1).next_state().census();
+        assert_eq!(pulsar.count("pulsar"), 1);
+        assert_eq!(pulsar.counts.values().sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn test_census_of_empty_board() {
+        assert_eq!(GameOfLife::dead(4, 4).census(), Census::default());
+    }
+}
@@ -0,0 +1,227 @@
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use crate::GameOfLife;
+
+impl GameOfLife {
+    /// Stamps `pattern`'s live cells onto this board with its top-left corner at `at_row`,
+    /// `at_col`, leaving cells under the pattern's dead cells unchanged.
+    ///
+    /// Any part of the pattern that falls outside this board is clipped.
+    pub fn overlay(&mut self, pattern: &GameOfLife, at_row: usize, at_col: usize) {
+        self.paste(pattern, at_row, at_col, |cell, alive| *cell |= alive);
+    }
+
+    /// Copies `pattern` onto this board with its top-left corner at `at_row`, `at_col`, so that,
+    /// unlike [`overlay`](GameOfLife::overlay), the pattern's dead cells kill whatever they
+    /// cover.
+    ///
+    /// Any part of the pattern that falls outside this board is clipped.
+    pub fn stamp(&mut self, pattern: &GameOfLife, at_row: usize, at_col: usize) {
+        self.paste(pattern, at_row, at_col, |cell, alive| *cell = alive);
+    }
+
+    /// Combines each cell of `pattern`, placed at `at_row`, `at_col`, into the cell under it.
+    fn paste(
+        &mut self,
+        pattern: &GameOfLife,
+        at_row: usize,
+        at_col: usize,
+        combine: impl Fn(&mut bool, bool),
+    ) {
+        for (row_num, row) in self.cells.iter_mut().enumerate().skip(at_row) {
+            let pattern_row = match pattern.cells().get(row_num - at_row) {
+                Some(pattern_row) => pattern_row,
+                None => break,
+            };
+            for (cell, &alive) in row.iter_mut().skip(at_col).zip(pattern_row) {
+                combine(cell, alive);
+            }
+        }
+    }
+
+    /// Moves every cell `drow` rows down and `dcol` columns right, or up and left for negative
+    /// offsets, keeping the board's size.
+    ///
+    /// Cells moved off the board are lost, and the cells they leave behind are dead.
+    pub fn shift(&mut self, drow: isize, dcol: isize) {
+        if (drow, dcol) == (0, 0) {
+            return;
+        }
+        let (rows, cols) = self.dimensions();
+        let source = |index: usize, offset: isize, len: usize| {
+            let source = (index as isize).checked_sub(offset)?;
+            usize::try_from(source).ok().filter(|&source| source < len)
+        };
+        let shifted = (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(
+                        |col| match (source(row, drow, rows), source(col, dcol, cols)) {
+                            (Some(source_row), Some(source_col)) => {
+                                self.cells[source_row][source_col]
+                            }
+                            _ => false,
+                        },
+                    )
+                    .collect()
+            })
+            .collect();
+        self.cells = shifted;
+    }
+
+    /// Kills every cell.
+    pub fn clear(&mut self) {
+        self.fill_rect(0, 0, usize::MAX, usize::MAX, false);
+    }
+
+    /// Sets every cell in the rectangle from `top`, `left` to `bottom`, `right` inclusive to
+    /// `alive`.
+    ///
+    /// The rectangle is clipped to the board, as in [`GameOfLife::population_in`].
+    pub fn fill_rect(&mut self, top: usize, left: usize, bottom: usize, right: usize, alive: bool) {
+        let rows = self
+            .cells
+            .iter_mut()
+            .take(bottom.saturating_add(1))
+            .skip(top);
+        for row in rows {
+            for cell in row.iter_mut().take(right.saturating_add(1)).skip(left) {
+                *cell = alive;
+            }
+        }
+    }
+
+    /// Changes this board to have `rows` rows and `cols` columns, keeping each cell at the same
+    /// position.
+    ///
+    /// Added cells are dead, and cells beyond the new bounds are discarded.
+    pub fn resize(&mut self, rows: usize, cols: usize) {
+        self.cells.resize_with(rows, Vec::new);
+        for row in &mut self.cells {
+            row.resize(cols, false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::GameOfLife;
+
+    #[test]
+    fn test_overlay() {
+        let mut game = GameOfLife::from_lines(&["•   ", "    ", "    "]);
+        game.overlay(&GameOfLife::from_lines(&["••", " •"]), 1, 1);
+        #[rustfmt::skip]
+        assert_eq!(game, GameOfLife::from_lines(&[
+            "•   ",
+            " •• ",
+            "  • ",
+        ]));
+    }
+
+    #[test]
+    fn test_overlay_clips_at_edges() {
+        let mut game = GameOfLife::from_lines(&["   ", "   "]);
+        game.overlay(&GameOfLife::from_lines(&["•••", "•••", "•••"]), 1, 2);
+        assert_eq!(game, GameOfLife::from_lines(&["   ", "  •"]));
+        game.overlay(&GameOfLife::from_lines(&["•"]), 5, 5);
+        assert_eq!(game, GameOfLife::from_lines(&["   ", "  •"]));
+    }
+
+    #[test]
+    fn test_stamp_overwrites() {
+        let mut game = GameOfLife::from_lines(&["••••"; 3]);
+        game.stamp(&GameOfLife::from_lines(&["• ", " •"]), 1, 3);
+        #[rustfmt::skip]
+        assert_eq!(game, GameOfLife::from_lines(&[
+            "••••",
+            "••••",
+            "••• ",
+        ]));
+    }
+
+    #[test]
+    fn test_stamped_gliders_collide() {
+        let glider = crate::patterns::glider();
+        let mut arena = GameOfLife::dead(100, 100);
+        arena.stamp(&glider, 40, 40);
+        arena.stamp(&glider.flip_horizontal(), 40, 50);
+        let apart = arena.nth_generation(8);
+        assert_eq!(apart.components(true), 2);
+        assert_eq!(apart.population(), 10);
+        // heading towards each other, they meet within a few generations and don't survive intact
+        assert_ne!(arena.nth_generation(40).population(), 10);
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut game = GameOfLife::from_lines(&["• ", " •"]);
+        game.resize(3, 4);
+        #[rustfmt::skip]
+        assert_eq!(game, GameOfLife::from_lines(&[
+            "•   ",
+            " •  ",
+            "    ",
+        ]));
+        game.resize(1, 1);
+        assert_eq!(game, GameOfLife::from_lines(&["•"]));
+        game.resize(0, 3);
+        assert_eq!(game.dimensions(), (0, 0));
+    }
+
+    #[test]
+    fn test_fill_rect_and_clear() {
+        let mut game = GameOfLife::from_lines(&["    ", "    ", "    "]);
+        game.fill_rect(1, 2, 5, 5, true);
+        game.fill_rect(0, 0, 0, 0, true);
+        game.fill_rect(2, 1, 1, 3, true);
+        #[rustfmt::skip]
+        assert_eq!(game, GameOfLife::from_lines(&[
+            "•   ",
+            "  ••",
+            "  ••",
+        ]));
+        game.clear();
+        assert!(game.is_extinct());
+        assert_eq!(game.dimensions(), (3, 4));
+    }
+
+    #[test]
+    fn test_filled_board_dies_of_overcrowding() {
+        let mut game = GameOfLife::from_lines(&["    "; 4]);
+        game.fill_rect(0, 0, 3, 3, true);
+        #[rustfmt::skip]
+        assert_eq!(game.next_state(), GameOfLife::from_lines(&[
+            "•  •",
+            "    ",
+            "    ",
+            "•  •",
+        ]));
+    }
+
+    #[test]
+    fn test_shift() {
+        #[rustfmt::skip]
+        let original = GameOfLife::from_lines(&[
+            "•• ",
+            " • ",
+            "   ",
+        ]);
+        let mut game = original.clone();
+        game.shift(0, 0);
+        assert_eq!(game, original);
+
+        game.shift(1, 1);
+        #[rustfmt::skip]
+        assert_eq!(game, GameOfLife::from_lines(&[
+            "   ",
+            " ••",
+            "  •",
+        ]));
+        game.shift(-2, 0);
+        assert_eq!(game, GameOfLife::from_lines(&["  •", "   ", "   "]));
+        game.shift(0, isize::MIN);
+        assert!(game.is_extinct());
+    }
+}
@@ -0,0 +1,57 @@
+//! Rendering boards straight to image files, for sharing results without capturing the
+//! terminal.
+//!
+//! These are free-function forms of [`GameOfLife::save_png`] and [`GameOfLife::save_gif`], which
+//! encode the images themselves rather than through an image crate, so they need nothing beyond
+//! the `std` feature.
+
+use std::io;
+use std::path::Path;
+
+use crate::GameOfLife;
+
+/// Writes `game` to the file at `path` as a PNG image with `cell_size` pixel square cells, as
+/// [`GameOfLife::save_png`] does.
+pub fn render_png(game: &GameOfLife, cell_size: u32, path: impl AsRef<Path>) -> io::Result<()> {
+    game.save_png(cell_size, path)
+}
+
+/// Writes `generations` successive generations of `game`, starting with `game` itself, to the
+/// file at `path` as a looping GIF animation, as [`GameOfLife::save_gif`] does.
+///
+/// Frames are shown `frame_delay_ms` milliseconds apart, rounded down to a multiple of 10.
+pub fn record_gif(
+    game: &GameOfLife,
+    generations: usize,
+    cell_size: u32,
+    frame_delay_ms: u16,
+    path: impl AsRef<Path>,
+) -> io::Result<()> {
+    game.save_gif(generations, cell_size, frame_delay_ms, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns;
+
+    #[test]
+    fn test_export_matches_encoders() {
+        let blinker = patterns::blinker().padded(1);
+        let path = std::env::temp_dir().join(format!("game_of_life_{}_export", std::process::id()));
+        let (png, gif) = (path.with_extension("png"), path.with_extension("gif"));
+
+        render_png(&blinker, 2, &png).unwrap();
+        let mut expected = Vec::new();
+        blinker.to_png(2, &mut expected).unwrap();
+        assert_eq!(std::fs::read(&png).unwrap(), expected);
+
+        record_gif(&blinker, 3, 2, 50, &gif).unwrap();
+        expected.clear();
+        blinker.to_gif(3, 2, 50, &mut expected).unwrap();
+        assert_eq!(std::fs::read(&gif).unwrap(), expected);
+
+        std::fs::remove_file(&png).unwrap();
+        std::fs::remove_file(&gif).unwrap();
+    }
+}
@@ -0,0 +1,157 @@
+use core::convert::TryFrom;
+
+use crate::{DimensionsError, GameOfLife, Rule};
+
+/// A board with `R` rows and `C` columns fixed at compile time, stored inline without any heap
+/// allocation.
+///
+/// Cells beyond the edges are always dead and neighbors are always the Moore neighborhood.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedLife<const R: usize, const C: usize> {
+    cells: [[bool; C]; R],
+    rule: Rule,
+}
+
+impl<const R: usize, const C: usize> FixedLife<R, C> {
+    /// Creates a board from its cells, evolving under Conway's rule.
+    pub fn new(cells: [[bool; C]; R]) -> FixedLife<R, C> {
+        FixedLife {
+            cells,
+            rule: Rule::conway(),
+        }
+    }
+
+    /// This board evolving under `rule` instead.
+    pub fn with_rule(self, rule: Rule) -> FixedLife<R, C> {
+        FixedLife { rule, ..self }
+    }
+
+    /// The board's cells, indexed as `cells()[row][col]`.
+    pub fn cells(&self) -> &[[bool; C]; R] {
+        &self.cells
+    }
+
+    /// The rule this board evolves under.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Computes the following generation, leaving this board unchanged.
+    pub fn next_state(&self) -> FixedLife<R, C> {
+        let mut cells = [[false; C]; R];
+        for (row_num, row) in cells.iter_mut().enumerate() {
+            for (col_num, cell) in row.iter_mut().enumerate() {
+                *cell = self.rule.is_alive_next(
+                    self.cells[row_num][col_num],
+                    self.count_living_neighbors(row_num, col_num),
+                );
+            }
+        }
+        FixedLife { cells, ..*self }
+    }
+
+    fn count_living_neighbors(&self, row_num: usize, col_num: usize) -> usize {
+        let rows = row_num.saturating_sub(1)..(row_num + 2).min(R);
+        rows.flat_map(|neighbor_row_num| {
+            let cols = col_num.saturating_sub(1)..(col_num + 2).min(C);
+            cols.filter(move |&neighbor_col_num| {
+                (neighbor_row_num, neighbor_col_num) != (row_num, col_num)
+                    && self.cells[neighbor_row_num][neighbor_col_num]
+            })
+        })
+        .count()
+    }
+}
+
+/// An entirely dead board.
+impl<const R: usize, const C: usize> Default for FixedLife<R, C> {
+    fn default() -> FixedLife<R, C> {
+        FixedLife::new([[false; C]; R])
+    }
+}
+
+/// Copies the cells and rule of a board that is exactly `R` by `C`; the boundary mode and
+/// neighborhood are not carried over.
+impl<const R: usize, const C: usize> TryFrom<&GameOfLife> for FixedLife<R, C> {
+    type Error = DimensionsError;
+
+    fn try_from(game: &GameOfLife) -> Result<FixedLife<R, C>, DimensionsError> {
+        let dimensions = game.dimensions();
+        // a board with no rows has no columns either
+        if dimensions != (R, C) && !(R == 0 && dimensions == (0, 0)) {
+            return Err(DimensionsError {
+                found: dimensions,
+                expected: (R, C),
+            });
+        }
+        let mut cells = [[false; C]; R];
+        for (row, game_row) in cells.iter_mut().zip(game.cells()) {
+            row.copy_from_slice(game_row);
+        }
+        Ok(FixedLife::new(cells).with_rule(game.rule()))
+    }
+}
+
+impl<const R: usize, const C: usize> From<&FixedLife<R, C>> for GameOfLife {
+    fn from(fixed: &FixedLife<R, C>) -> GameOfLife {
+        GameOfLife::from_cells(fixed.cells.iter().map(|row| row.to_vec()).collect())
+            .with_rule(fixed.rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blinker_oscillates() {
+        let vertical = FixedLife::new([
+            [false, true, false],
+            [false, true, false],
+            [false, true, false],
+        ]);
+        let horizontal = FixedLife::new([
+            [false, false, false],
+            [true, true, true],
+            [false, false, false],
+        ]);
+        assert_eq!(vertical.next_state(), horizontal);
+        assert_eq!(horizontal.next_state(), vertical);
+    }
+
+    #[test]
+    fn test_matches_game_of_life() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            " •    ",
+            "  •   ",
+            "•••   ",
+            "      ",
+            "     •",
+        ]);
+        let fixed = FixedLife::<5, 6>::try_from(&game).unwrap();
+        assert_eq!(GameOfLife::from(&fixed.next_state()), game.next_state());
+
+        let highlife = game.with_rule(Rule::parse("B36/S23").unwrap());
+        let fixed = FixedLife::<5, 6>::try_from(&highlife).unwrap();
+        assert_eq!(fixed.rule(), highlife.rule());
+        assert_eq!(GameOfLife::from(&fixed), highlife);
+    }
+
+    #[test]
+    fn test_try_from_checks_dimensions() {
+        let game = GameOfLife::from_lines(&["••", "••"]);
+        assert_eq!(
+            FixedLife::<2, 3>::try_from(&game),
+            Err(DimensionsError {
+                found: (2, 2),
+                expected: (2, 3),
+            })
+        );
+        assert!(FixedLife::<0, 0>::try_from(&GameOfLife::from_lines(&[])).is_ok());
+        assert_eq!(
+            FixedLife::<2, 2>::default().next_state().cells(),
+            &[[false; 2]; 2]
+        );
+    }
+}
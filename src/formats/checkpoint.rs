@@ -0,0 +1,317 @@
+//! A compact, versioned binary snapshot of a board and all of its settings.
+//!
+//! A checkpoint is the 4 byte magic number `GoLc` and a version byte, followed by the rows and
+//! columns as little-endian `u64`s, the birth and survival bit sets of the rule as
+//! little-endian `u16`s, one byte each for the boundary mode and neighborhood, the radius as a
+//! little-endian `u32`, a byte of flags whose lowest bit is set for an auto-growing board, and
+//! finally the cells in row-major order, 8 to a byte starting from the least significant bit.
+//!
+//! Version 1 checkpoints, which have no flags byte, are still read as boards that don't grow.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt;
+
+use crate::{BoundaryMode, GameOfLife, Neighborhood, Rule};
+
+const MAGIC: &[u8; 4] = b"GoLc";
+const VERSION: u8 = 2;
+
+/// Set in the flags byte for a board that [auto-grows](GameOfLife::with_auto_grow).
+const AUTO_GROW: u8 = 1;
+
+impl GameOfLife {
+    /// Writes this board, along with its rule, boundary mode, neighborhood, radius and whether
+    /// it auto-grows, as a compact binary checkpoint that [`GameOfLife::from_checkpoint`] restores.
+    pub fn checkpoint(&self) -> Vec<u8> {
+        let (rows, cols) = self.dimensions();
+        let (birth, survival) = self.rule.to_bits();
+        let mut output = Vec::with_capacity(32 + (rows * cols).div_ceil(8));
+        output.extend_from_slice(MAGIC);
+        output.push(VERSION);
+        output.extend_from_slice(&(rows as u64).to_le_bytes());
+        output.extend_from_slice(&(cols as u64).to_le_bytes());
+        output.extend_from_slice(&birth.to_le_bytes());
+        output.extend_from_slice(&survival.to_le_bytes());
+        output.push(match self.boundary {
+            BoundaryMode::Dead => 0,
+            BoundaryMode::Toroidal => 1,
+            BoundaryMode::Mirror => 2,
+            BoundaryMode::KleinBottle => 3,
+        });
+        output.push(match self.neighborhood {
+            Neighborhood::Moore => 0,
+            Neighborhood::VonNeumann => 1,
+        });
+        output.extend_from_slice(&(self.radius as u32).to_le_bytes());
+        output.push(if self.auto_grow { AUTO_GROW } else { 0 });
+        let mut cells = self.cells.iter().flatten();
+        loop {
+            let byte = cells
+                .by_ref()
+                .take(8)
+                .enumerate()
+                .fold(None, |byte, (bit, &alive)| {
+                    Some(byte.unwrap_or(0) | (alive as u8) << bit)
+                });
+            match byte {
+                Some(byte) => output.push(byte),
+                None => break,
+            }
+        }
+        output
+    }
+
+    /// Restores a board written by [`GameOfLife::checkpoint`].
+    pub fn from_checkpoint(input: &[u8]) -> Result<GameOfLife, CheckpointError> {
+        let mut reader = Reader { input };
+        if reader.take(MAGIC.len()).ok() != Some(&MAGIC[..]) {
+            return Err(CheckpointError::NotACheckpoint);
+        }
+        let version = match reader.take(1)?[0] {
+            version @ (1 | VERSION) => version,
+            version => return Err(CheckpointError::UnsupportedVersion(version)),
+        };
+        let rows = reader.dimension()?;
+        let cols = reader.dimension()?;
+        let birth = reader.u16()?;
+        let survival = reader.u16()?;
+        let rule = Rule::from_bits(birth, survival).ok_or(CheckpointError::Malformed)?;
+        let boundary = match reader.take(1)?[0] {
+            0 => BoundaryMode::Dead,
+            1 => BoundaryMode::Toroidal,
+            2 => BoundaryMode::Mirror,
+            3 => BoundaryMode::KleinBottle,
+            _ => return Err(CheckpointError::Malformed),
+        };
+        let neighborhood = match reader.take(1)?[0] {
+            0 => Neighborhood::Moore,
+            1 => Neighborhood::VonNeumann,
+            _ => return Err(CheckpointError::Malformed),
+        };
+        let radius = u32::from_le_bytes(reader.array()?);
+        let radius = usize::try_from(radius).map_err(|_| CheckpointError::Malformed)?;
+        let flags = if version == 1 { 0 } else { reader.take(1)?[0] };
+        if flags & !AUTO_GROW != 0 {
+            return Err(CheckpointError::Malformed);
+        }
+        // rows with no columns take no cell bytes, but still take room once unpacked
+        if !super::within_size_limit(rows, cols) {
+            return Err(CheckpointError::Malformed);
+        }
+        let len = (rows * cols).div_ceil(8);
+        // the length is checked before anything is allocated for the cells
+        let bytes = reader.take(len)?;
+        if !reader.input.is_empty() {
+            return Err(CheckpointError::Malformed);
+        }
+        let cells = (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(|col| {
+                        let index = row * cols + col;
+                        bytes[index / 8] & 1 << (index % 8) != 0
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(GameOfLife::from_cells(cells)
+            .with_rule(rule)
+            .with_boundary(boundary)
+            .with_neighborhood(neighborhood)
+            .with_radius(radius)
+            .with_auto_grow(flags & AUTO_GROW != 0))
+    }
+}
+
+/// Reads fields from the front of a checkpoint.
+struct Reader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CheckpointError> {
+        if self.input.len() < len {
+            return Err(CheckpointError::Truncated);
+        }
+        let (taken, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], CheckpointError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn u16(&mut self) -> Result<u16, CheckpointError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    /// A row or column count, which must fit in a `usize`.
+    fn dimension(&mut self) -> Result<usize, CheckpointError> {
+        usize::try_from(u64::from_le_bytes(self.array()?)).map_err(|_| CheckpointError::Malformed)
+    }
+}
+
+/// Returned when a checkpoint cannot be restored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckpointError {
+    /// The input didn't start with the checkpoint magic number.
+    NotACheckpoint,
+    /// The checkpoint was written in a format version this crate doesn't know.
+    UnsupportedVersion(u8),
+    /// The input ended before the whole board was read.
+    Truncated,
+    /// A setting held an unknown value, the board would take more than
+    /// [`MAX_BOARD_BYTES`](crate::MAX_BOARD_BYTES), or bytes followed the cells.
+    Malformed,
+}
+
+impl fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckpointError::NotACheckpoint => f.write_str("input is not a checkpoint"),
+            CheckpointError::UnsupportedVersion(version) => {
+                write!(f, "checkpoint version {} is not supported", version)
+            }
+            CheckpointError::Truncated => f.write_str("checkpoint is truncated"),
+            CheckpointError::Malformed => f.write_str("checkpoint is malformed"),
+        }
+    }
+}
+
+impl Error for CheckpointError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SplitMix64;
+
+    #[test]
+    fn test_checkpoint_round_trips() {
+        let mut rng = SplitMix64::new(93);
+        for &(rows, cols) in &[(0, 0), (0, 5), (1, 1), (3, 3), (7, 9), (16, 16)] {
+            let game = GameOfLife::random(rows, cols, 0.4, &mut rng);
+            let checkpoint = game.checkpoint();
+            assert_eq!(checkpoint.len(), 32 + (rows * cols).div_ceil(8));
+            assert_eq!(GameOfLife::from_checkpoint(&checkpoint), Ok(game));
+        }
+
+        let game = GameOfLife::random(5, 6, 0.5, &mut rng)
+            .with_rule(Rule::parse("B36/S23").unwrap())
+            .with_boundary(BoundaryMode::Mirror)
+            .with_neighborhood(Neighborhood::VonNeumann)
+            .with_radius(2);
+        assert_eq!(GameOfLife::from_checkpoint(&game.checkpoint()), Ok(game));
+
+        let grown = crate::patterns::glider()
+            .with_auto_grow(true)
+            .nth_generation(4);
+        assert_eq!(GameOfLife::from_checkpoint(&grown.checkpoint()), Ok(grown));
+    }
+
+    #[test]
+    fn test_reads_version_1() {
+        let game = GameOfLife::from_lines(&["• ", " •", "• "]).with_auto_grow(true);
+        let mut version_1 = game.checkpoint();
+        version_1[4] = 1;
+        version_1.remove(31);
+        assert_eq!(
+            GameOfLife::from_checkpoint(&version_1),
+            Ok(game.with_auto_grow(false))
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_layout() {
+        let game = GameOfLife::from_lines(&["• ", " •", "• "]);
+        let checkpoint = game.checkpoint();
+        assert_eq!(&checkpoint[..5], b"GoLc\x02");
+        assert_eq!(checkpoint[5..13], 3u64.to_le_bytes());
+        assert_eq!(checkpoint[13..21], 2u64.to_le_bytes());
+        assert_eq!(checkpoint[21..25], [0b1000, 0, 0b1100, 0]);
+        assert_eq!(checkpoint[25..31], [0, 0, 1, 0, 0, 0]);
+        assert_eq!(checkpoint[31], 0);
+        assert_eq!(checkpoint[32..], [0b01_1001]);
+        assert_eq!(game.with_auto_grow(true).checkpoint()[31], 1);
+    }
+
+    #[test]
+    fn test_from_checkpoint_rejects_invalid_input() {
+        let checkpoint = GameOfLife::from_lines(&["• •"; 4]).checkpoint();
+        assert_eq!(
+            GameOfLife::from_checkpoint(b"#Life 1.06"),
+            Err(CheckpointError::NotACheckpoint)
+        );
+        assert_eq!(
+            GameOfLife::from_checkpoint(&[]),
+            Err(CheckpointError::NotACheckpoint)
+        );
+
+        let mut future = checkpoint.clone();
+        future[4] = 3;
+        assert_eq!(
+            GameOfLife::from_checkpoint(&future),
+            Err(CheckpointError::UnsupportedVersion(3))
+        );
+
+        for len in 5..checkpoint.len() {
+            assert_eq!(
+                GameOfLife::from_checkpoint(&checkpoint[..len]),
+                Err(CheckpointError::Truncated)
+            );
+        }
+
+        let mut trailing = checkpoint.clone();
+        trailing.push(0);
+        assert_eq!(
+            GameOfLife::from_checkpoint(&trailing),
+            Err(CheckpointError::Malformed)
+        );
+
+        let mut boundary = checkpoint.clone();
+        boundary[25] = 4;
+        assert_eq!(
+            GameOfLife::from_checkpoint(&boundary),
+            Err(CheckpointError::Malformed)
+        );
+
+        let mut flags = checkpoint.clone();
+        flags[31] = 2;
+        assert_eq!(
+            GameOfLife::from_checkpoint(&flags),
+            Err(CheckpointError::Malformed)
+        );
+
+        let mut rule = checkpoint.clone();
+        rule[22] = 0b10;
+        assert_eq!(
+            GameOfLife::from_checkpoint(&rule),
+            Err(CheckpointError::Malformed)
+        );
+
+        // huge dimensions are rejected without allocating room for them
+        let mut huge = checkpoint;
+        huge[5..21].copy_from_slice(&[0xff; 16]);
+        assert_eq!(
+            GameOfLife::from_checkpoint(&huge),
+            Err(CheckpointError::Malformed)
+        );
+        // as are dimensions that could be allocated, but that the input holds too few cells for
+        huge[5..21].copy_from_slice(&[0x7f, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            GameOfLife::from_checkpoint(&huge),
+            Err(CheckpointError::Truncated)
+        );
+        // a board with no columns needs no cell bytes, however many rows it claims
+        let mut no_cols = GameOfLife::dead(1, 0).checkpoint();
+        no_cols[5..13].copy_from_slice(&(1u64 << 40).to_le_bytes());
+        assert_eq!(
+            GameOfLife::from_checkpoint(&no_cols),
+            Err(CheckpointError::Malformed)
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use crate::GameOfLife;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Rle,
+    Plaintext,
+    Life106,
+}
+
+impl Format {
+    fn from_extension(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "rle" => Some(Format::Rle),
+            "cells" => Some(Format::Plaintext),
+            "lif" | "life" => Some(Format::Life106),
+            _ => None,
+        }
+    }
+
+    /// Guesses the format from the start of the file: Life 1.06 has a `#Life 1.06` header and
+    /// RLE an `x = ..` header after any `#` comment lines.
+    fn sniff(contents: &str) -> Format {
+        let first_line = contents
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'));
+        if contents.starts_with("#Life 1.06") {
+            Format::Life106
+        } else if first_line.is_some_and(|line| line.starts_with('x')) {
+            Format::Rle
+        } else {
+            Format::Plaintext
+        }
+    }
+
+    fn parse(self, contents: &str) -> io::Result<GameOfLife> {
+        match self {
+            Format::Rle => GameOfLife::from_rle(contents).map_err(invalid_data),
+            Format::Plaintext => Ok(GameOfLife::from_plaintext(contents)),
+            Format::Life106 => GameOfLife::from_life106_cropped(contents).map_err(invalid_data),
+        }
+    }
+}
+
+fn invalid_data(err: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+impl GameOfLife {
+    /// Reads a pattern from a file in RLE (`.rle`), plaintext (`.cells`) or Life 1.06 (`.lif` or
+    /// `.life`) format, recognizing the format by its extension, or failing that, its header.
+    ///
+    /// Life 1.06 patterns have no size of their own, so they are loaded onto a board just large
+    /// enough to hold their live cells. Malformed contents are an [`io::ErrorKind::InvalidData`]
+    /// error.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<GameOfLife> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)?;
+        Format::from_extension(path)
+            .unwrap_or_else(|| Format::sniff(&contents))
+            .parse(&contents)
+    }
+
+    /// Reads a pattern, in any of the formats [`from_file`](GameOfLife::from_file) accepts, from
+    /// `reader`, recognizing the format by its header.
+    pub fn read_pattern(mut reader: impl Read) -> io::Result<GameOfLife> {
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+        Format::sniff(&contents).parse(&contents)
+    }
+
+    /// Writes this board to a file in the format given by its extension: `.rle`, `.cells`,
+    /// `.lif` or `.life`. Any other extension is an [`io::ErrorKind::InvalidInput`] error.
+    pub fn to_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let contents = match Format::from_extension(path) {
+            Some(Format::Rle) => self.to_rle(),
+            Some(Format::Plaintext) => self.to_plaintext(),
+            Some(Format::Life106) => self.to_life106(),
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "pattern files must end in .rle, .cells, .lif or .life",
+                ))
+            }
+        };
+        fs::write(path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io;
+    use std::path::PathBuf;
+
+    use crate::{patterns, GameOfLife};
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("game_of_life_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_files_round_trip() {
+        let glider = patterns::glider().padded(2);
+        for name in &["glider.rle", "glider.cells"] {
+            let path = temp_path(name);
+            glider.to_file(&path).unwrap();
+            assert_eq!(GameOfLife::from_file(&path).unwrap(), glider);
+            fs::remove_file(path).unwrap();
+        }
+
+        let path = temp_path("glider.lif");
+        glider.to_file(&path).unwrap();
+        assert_eq!(GameOfLife::from_file(&path).unwrap(), patterns::glider());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_from_file_sniffs_format() {
+        let glider = patterns::glider();
+        for (name, contents) in &[
+            ("rle.txt", glider.to_rle()),
+            ("cells.txt", glider.to_plaintext()),
+            ("life106.txt", glider.to_life106()),
+        ] {
+            let path = temp_path(name);
+            fs::write(&path, contents).unwrap();
+            assert_eq!(GameOfLife::from_file(&path).unwrap(), glider);
+            fs::remove_file(path).unwrap();
+            assert_eq!(
+                GameOfLife::read_pattern(contents.as_bytes()).unwrap(),
+                glider
+            );
+        }
+        let err = GameOfLife::read_pattern(&b"#Life 1.06\nx y\n"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_file_errors() {
+        let path = temp_path("bad.rle");
+        fs::write(&path, "x = 1, y = 1\n2o!").unwrap();
+        let err = GameOfLife::from_file(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(path).unwrap();
+
+        let err = patterns::glider()
+            .to_file(temp_path("glider.txt"))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,295 @@
+//! A compact JSON form listing a board's size and live cells, such as
+//! `{"rows":3,"cols":3,"live":[[0,1],[1,2]]}`.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::GameOfLife;
+
+impl GameOfLife {
+    /// Writes this board as a JSON object with its `rows`, `cols` and the `[row, col]`
+    /// coordinates of each `live` cell in row-major order.
+    pub fn to_json_coords(&self) -> String {
+        let (rows, cols) = self.dimensions();
+        let live: Vec<String> = self
+            .live_cells()
+            .iter()
+            .map(|(row, col)| format!("[{},{}]", row, col))
+            .collect();
+        format!(
+            "{{\"rows\":{},\"cols\":{},\"live\":[{}]}}",
+            rows,
+            cols,
+            live.join(",")
+        )
+    }
+
+    /// Parses a board from the JSON written by [`GameOfLife::to_json_coords`].
+    ///
+    /// The fields may come in any order, and fields other than `rows`, `cols` and `live` are
+    /// ignored. A board that would take more than [`MAX_BOARD_BYTES`](crate::MAX_BOARD_BYTES) is
+    /// an error.
+    pub fn from_json_coords(input: &str) -> Result<GameOfLife, JsonError> {
+        let mut parser = Parser { input, pos: 0 };
+        let (mut rows, mut cols, mut live) = (None, None, None);
+        parser.expect(b'{')?;
+        if !parser.eat(b'}') {
+            loop {
+                let key = parser.string()?;
+                parser.expect(b':')?;
+                match key {
+                    "rows" => rows = Some(parser.number()?),
+                    "cols" => cols = Some(parser.number()?),
+                    "live" => live = Some(parser.coords()?),
+                    _ => parser.skip_value()?,
+                }
+                if parser.eat(b'}') {
+                    break;
+                }
+                parser.expect(b',')?;
+            }
+        }
+        parser.end()?;
+        let rows = rows.ok_or(JsonError::MissingField("rows"))?;
+        let cols = cols.ok_or(JsonError::MissingField("cols"))?;
+        let live = live.ok_or(JsonError::MissingField("live"))?;
+        if !super::within_size_limit(rows, cols) {
+            return Err(JsonError::TooLarge { rows, cols });
+        }
+        GameOfLife::from_coords(rows, cols, &live).map_err(|err| JsonError::OutOfBounds {
+            row: err.row,
+            col: err.col,
+        })
+    }
+}
+
+/// Reads the small subset of JSON that boards are written in.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn malformed(&self) -> JsonError {
+        JsonError::Malformed { offset: self.pos }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    /// Consumes `byte` if it comes next.
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), JsonError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.malformed())
+        }
+    }
+
+    fn end(&mut self) -> Result<(), JsonError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.malformed()),
+        }
+    }
+
+    /// A string without escapes, which is all that field names need.
+    fn string(&mut self) -> Result<&'a str, JsonError> {
+        self.expect(b'"')?;
+        let start = self.pos;
+        let len = self.input[start..]
+            .find(['"', '\\'])
+            .ok_or_else(|| self.malformed())?;
+        self.pos += len;
+        self.expect(b'"')?;
+        Ok(&self.input[start..start + len])
+    }
+
+    /// A non-negative integer.
+    fn number(&mut self) -> Result<usize, JsonError> {
+        self.skip_whitespace();
+        let rest = &self.input[self.pos..];
+        let len = rest
+            .find(|char: char| !char.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let number = rest[..len].parse().map_err(|_| self.malformed())?;
+        self.pos += len;
+        Ok(number)
+    }
+
+    /// An array of `[row, col]` pairs.
+    fn coords(&mut self) -> Result<Vec<(usize, usize)>, JsonError> {
+        let mut coords = Vec::new();
+        self.expect(b'[')?;
+        if self.eat(b']') {
+            return Ok(coords);
+        }
+        loop {
+            self.expect(b'[')?;
+            let row = self.number()?;
+            self.expect(b',')?;
+            let col = self.number()?;
+            self.expect(b']')?;
+            coords.push((row, col));
+            if self.eat(b']') {
+                return Ok(coords);
+            }
+            self.expect(b',')?;
+        }
+    }
+
+    /// Skips over a value of any type, as long as it contains no strings with escapes.
+    fn skip_value(&mut self) -> Result<(), JsonError> {
+        let mut depth = 0;
+        loop {
+            match self.peek().ok_or_else(|| self.malformed())? {
+                b'"' => {
+                    self.string()?;
+                }
+                b'{' | b'[' => {
+                    depth += 1;
+                    self.pos += 1;
+                }
+                b'}' | b']' if depth > 0 => {
+                    depth -= 1;
+                    self.pos += 1;
+                }
+                b',' | b':' if depth > 0 => self.pos += 1,
+                _ => {
+                    let rest = &self.input[self.pos..];
+                    let len = rest
+                        .find(|char: char| !(char.is_ascii_alphanumeric() || "+-.".contains(char)))
+                        .unwrap_or(rest.len());
+                    if len == 0 {
+                        return Err(self.malformed());
+                    }
+                    self.pos += len;
+                }
+            }
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Returned when JSON input cannot be parsed as a board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonError {
+    /// The input wasn't a JSON object of the expected shape; `offset` is the byte where parsing
+    /// stopped.
+    Malformed { offset: usize },
+    /// The `rows`, `cols` or `live` field was missing.
+    MissingField(&'static str),
+    /// A live cell lay outside the board given by `rows` and `cols`.
+    OutOfBounds { row: usize, col: usize },
+    /// The `rows` and `cols` fields give a board larger than
+    /// [`MAX_BOARD_BYTES`](crate::MAX_BOARD_BYTES).
+    TooLarge { rows: usize, cols: usize },
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonError::Malformed { offset } => write!(f, "malformed board JSON at byte {}", offset),
+            JsonError::MissingField(field) => write!(f, "missing {:?} field", field),
+            JsonError::OutOfBounds { row, col } => {
+                write!(f, "cell ({}, {}) is outside the board", row, col)
+            }
+            JsonError::TooLarge { rows, cols } => {
+                write!(f, "a {}x{} board is too large to load", rows, cols)
+            }
+        }
+    }
+}
+
+impl Error for JsonError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trips() {
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            " • ",
+            "  •",
+            "•••",
+            "   ",
+        ]);
+        let json = glider.to_json_coords();
+        assert_eq!(
+            json,
+            r#"{"rows":4,"cols":3,"live":[[0,1],[1,2],[2,0],[2,1],[2,2]]}"#
+        );
+        assert_eq!(GameOfLife::from_json_coords(&json), Ok(glider));
+
+        let empty = GameOfLife::from_lines(&[]);
+        assert_eq!(empty.to_json_coords(), r#"{"rows":0,"cols":0,"live":[]}"#);
+        assert_eq!(
+            GameOfLife::from_json_coords(&empty.to_json_coords()),
+            Ok(empty)
+        );
+    }
+
+    #[test]
+    fn test_from_json_coords_is_flexible() {
+        let json = r#" {
+            "name": "diagonal", "live": [ [0, 0], [1, 1] ],
+            "meta": {"tags": ["a", 1, -2.5e3, true, null, {}]},
+            "cols": 2, "rows": 2
+        } "#;
+        assert_eq!(
+            GameOfLife::from_json_coords(json),
+            Ok(GameOfLife::from_lines(&["• ", " •"]))
+        );
+    }
+
+    #[test]
+    fn test_from_json_coords_rejects_invalid_input() {
+        assert_eq!(
+            GameOfLife::from_json_coords(r#"{"rows":1,"cols":1}"#),
+            Err(JsonError::MissingField("live"))
+        );
+        assert_eq!(
+            GameOfLife::from_json_coords(r#"{"rows":1,"cols":1,"live":[[1,0]]}"#),
+            Err(JsonError::OutOfBounds { row: 1, col: 0 })
+        );
+        assert_eq!(
+            GameOfLife::from_json_coords(r#"{"rows":-1}"#),
+            Err(JsonError::Malformed { offset: 8 })
+        );
+        assert_eq!(
+            GameOfLife::from_json_coords(r#"{"rows":1,"cols":1,"live":[]} x"#),
+            Err(JsonError::Malformed { offset: 30 })
+        );
+        assert_eq!(
+            GameOfLife::from_json_coords(r#"{"rows":100000000000,"cols":100000000000,"live":[]}"#),
+            Err(JsonError::TooLarge {
+                rows: 100_000_000_000,
+                cols: 100_000_000_000
+            })
+        );
+        assert!(GameOfLife::from_json_coords(r#"{"rows":1,"cols":1,"live":[[0]]}"#).is_err());
+        assert!(GameOfLife::from_json_coords("").is_err());
+    }
+}
@@ -0,0 +1,199 @@
+//! The Life 1.06 format, described at <https://conwaylife.com/wiki/Life_1.06>.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt;
+
+use crate::GameOfLife;
+
+const HEADER: &str = "#Life 1.06";
+
+impl GameOfLife {
+    /// Parses a list of live cells in Life 1.06 format onto a blank board of `bounds`, given as
+    /// `(rows, cols)`.
+    ///
+    /// Each line after the `#Life 1.06` header holds the `x y` coordinates of one live cell, where
+    /// `x` is the column and `y` is the row, apart from `#` lines such as `#D` descriptions. Life
+    /// 1.06 patterns are unbounded, so coordinates that are negative or don't fit within `bounds`
+    /// are an error.
+    pub fn from_life106(input: &str, bounds: (usize, usize)) -> Result<GameOfLife, Life106Error> {
+        let (rows, cols) = bounds;
+        let mut game = GameOfLife::dead(rows, cols);
+        for (line, x, y) in parse_coords(input)? {
+            let out_of_bounds = Life106Error::OutOfBounds { line, x, y };
+            match (usize::try_from(y), usize::try_from(x)) {
+                (Ok(row), Ok(col)) => game.set(row, col, true).map_err(|_| out_of_bounds)?,
+                _ => return Err(out_of_bounds),
+            }
+        }
+        Ok(game)
+    }
+
+    /// Parses a list of live cells in Life 1.06 format onto a board just large enough to hold
+    /// them, translating the pattern so its topmost and leftmost live cells are in row and
+    /// column 0.
+    #[cfg(feature = "std")]
+    pub(crate) fn from_life106_cropped(input: &str) -> Result<GameOfLife, Life106Error> {
+        let coords = parse_coords(input)?;
+        let min_x = coords.iter().map(|&(_, x, _)| x).min().unwrap_or(0);
+        let min_y = coords.iter().map(|&(_, _, y)| y).min().unwrap_or(0);
+        let mut cells = Vec::new();
+        for (line, x, y) in coords {
+            let offset = |coord: i64, min: i64| {
+                coord
+                    .checked_sub(min)
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .ok_or(Life106Error::OutOfBounds { line, x, y })
+            };
+            let (row, col) = (offset(y, min_y)?, offset(x, min_x)?);
+            cells.push((row, col));
+        }
+        let rows = cells.iter().map(|&(row, _)| row + 1).max().unwrap_or(0);
+        let cols = cells.iter().map(|&(_, col)| col + 1).max().unwrap_or(0);
+        if !super::within_size_limit(rows, cols) {
+            return Err(Life106Error::TooLarge {
+                width: cols,
+                height: rows,
+            });
+        }
+        let mut game = GameOfLife::dead(rows, cols);
+        for (row, col) in cells {
+            game.cells[row][col] = true;
+        }
+        Ok(game)
+    }
+
+    /// Writes this board's live cells in Life 1.06 format, in row-major order.
+    pub fn to_life106(&self) -> String {
+        let mut output = format!("{}\n", HEADER);
+        for (row, col) in self.live_cells() {
+            output.push_str(&format!("{} {}\n", col, row));
+        }
+        output
+    }
+}
+
+/// Parses the header and every `x y` line, returning each line number along with its coordinates.
+fn parse_coords(input: &str) -> Result<Vec<(usize, i64, i64)>, Life106Error> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()));
+    match lines.next() {
+        Some((_, HEADER)) => {}
+        _ => return Err(Life106Error::MissingHeader),
+    }
+    lines
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_num, line)| {
+            let mut coords = line.split_whitespace().map(str::parse::<i64>);
+            match (coords.next(), coords.next(), coords.next()) {
+                (Some(Ok(x)), Some(Ok(y)), None) => Ok((line_num, x, y)),
+                _ => Err(Life106Error::InvalidLine { line: line_num }),
+            }
+        })
+        .collect()
+}
+
+/// Returned when Life 1.06 input cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Life106Error {
+    /// The input did not start with `#Life 1.06`.
+    MissingHeader,
+    /// A line was not a pair of integer coordinates.
+    InvalidLine { line: usize },
+    /// A cell's coordinates were negative or outside the requested bounds.
+    OutOfBounds { line: usize, x: i64, y: i64 },
+    /// The live cells are spread too far apart for a board holding them all to fit within
+    /// [`MAX_BOARD_BYTES`](crate::MAX_BOARD_BYTES).
+    TooLarge { width: usize, height: usize },
+}
+
+impl fmt::Display for Life106Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Life106Error::MissingHeader => write!(f, "missing {:?} header", HEADER),
+            Life106Error::InvalidLine { line } => {
+                write!(f, "line {}: expected a pair of integer coordinates", line)
+            }
+            Life106Error::OutOfBounds { line, x, y } => {
+                write!(f, "line {}: cell ({}, {}) is outside the board", line, x, y)
+            }
+            Life106Error::TooLarge { width, height } => {
+                write!(f, "a {}x{} board is too large to load", width, height)
+            }
+        }
+    }
+}
+
+impl Error for Life106Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_life106_round_trips() {
+        let life106 = "#Life 1.06\n1 0\n2 1\n0 2\n1 2\n2 2\n";
+        let glider = GameOfLife::from_life106(life106, (4, 3)).unwrap();
+        #[rustfmt::skip]
+        assert_eq!(glider, GameOfLife::from_lines(&[
+            " • ",
+            "  •",
+            "•••",
+            "   ",
+        ]));
+        assert_eq!(glider.to_life106(), life106);
+
+        let described = "#Life 1.06\n#D Name: Glider\n#D\n1 0\n2 1\n0 2\n1 2\n2 2\n";
+        assert_eq!(GameOfLife::from_life106(described, (4, 3)), Ok(glider));
+    }
+
+    #[test]
+    fn test_from_life106_errors() {
+        assert_eq!(
+            GameOfLife::from_life106("0 0\n", (1, 1)),
+            Err(Life106Error::MissingHeader)
+        );
+        assert_eq!(
+            GameOfLife::from_life106("#Life 1.06\n0 0 0\n", (1, 1)),
+            Err(Life106Error::InvalidLine { line: 2 })
+        );
+        assert_eq!(
+            GameOfLife::from_life106("#Life 1.06\n0 0\n-1 0\n", (1, 1)),
+            Err(Life106Error::OutOfBounds {
+                line: 3,
+                x: -1,
+                y: 0
+            })
+        );
+        assert_eq!(
+            GameOfLife::from_life106("#Life 1.06\n3 0\n", (5, 3)),
+            Err(Life106Error::OutOfBounds {
+                line: 2,
+                x: 3,
+                y: 0
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_cropped_rejects_huge_spreads() {
+        let life106 = "#Life 1.06\n-2 5\n-1 6\n";
+        assert_eq!(
+            GameOfLife::from_life106_cropped(life106),
+            Ok(GameOfLife::from_lines(&["• ", " •"]))
+        );
+        assert_eq!(
+            GameOfLife::from_life106_cropped("#Life 1.06\n0 0\n100000000000 100000000000\n"),
+            Err(Life106Error::TooLarge {
+                width: 100_000_000_001,
+                height: 100_000_000_001
+            })
+        );
+    }
+}
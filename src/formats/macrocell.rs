@@ -0,0 +1,317 @@
+//! Golly's macrocell format, described at <https://conwaylife.com/wiki/Macrocell>.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::{GameOfLife, Rule, RuleError};
+
+const HEADER: &str = "[M2]";
+
+/// The level of the 8x8 squares that leaf lines describe.
+const LEAF_LEVEL: u32 = 3;
+
+/// A square of `2^level` cells across, as defined by one line of a macrocell file.
+enum Node {
+    /// An 8x8 square, one byte per row with bit `col` set for each live cell.
+    Leaf([u8; 8]),
+    /// A larger square built from the north-west, north-east, south-west and south-east
+    /// quadrants, each the index of an earlier node or 0 for an empty one.
+    Branch { level: u32, children: [usize; 4] },
+}
+
+impl GameOfLife {
+    /// Parses a pattern in Golly's macrocell format onto a blank board of `bounds`, given as
+    /// `(rows, cols)`.
+    ///
+    /// After the `[M2]` header, lines starting with `#` are comments, except that `#R` sets the
+    /// board's rule. Every other line defines a node of the quadtree, and the last one is
+    /// expanded onto the board, translated so its topmost and leftmost live cells are in row
+    /// and column 0. A pattern that doesn't then fit within `bounds` is an error.
+    pub fn from_macrocell(
+        input: &str,
+        bounds: (usize, usize),
+    ) -> Result<GameOfLife, MacrocellError> {
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()));
+        match lines.next() {
+            Some((_, header)) if header.starts_with(HEADER) => {}
+            _ => return Err(MacrocellError::MissingHeader),
+        }
+        let mut rule = Rule::default();
+        // node 0 is the empty node of any level
+        let mut nodes = vec![None];
+        let mut populations = vec![0];
+        for (line_num, line) in lines {
+            if let Some(rulestring) = line.strip_prefix("#R") {
+                rule = Rule::parse(rulestring).map_err(MacrocellError::InvalidRule)?;
+                continue;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let node =
+                parse_node(line, &nodes).ok_or(MacrocellError::InvalidLine { line: line_num })?;
+            populations.push(population(&node, &populations));
+            nodes.push(Some(node));
+        }
+
+        let (rows, cols) = bounds;
+        let mut game = GameOfLife::dead(rows, cols).with_rule(rule);
+        let top = nodes.len() - 1;
+        if populations[top] > rows.saturating_mul(cols) as u64 {
+            return Err(MacrocellError::ExceedsBounds);
+        }
+        let mut cells = Vec::new();
+        collect_live(&nodes, &populations, top, (0, 0), &mut cells);
+        let min_row = cells.iter().map(|&(row, _)| row).min().unwrap_or(0);
+        let min_col = cells.iter().map(|&(_, col)| col).min().unwrap_or(0);
+        for (row, col) in cells {
+            let (row, col) = (row - min_row, col - min_col);
+            if row >= rows as u64 || col >= cols as u64 {
+                return Err(MacrocellError::ExceedsBounds);
+            }
+            game.cells[row as usize][col as usize] = true;
+        }
+        Ok(game)
+    }
+}
+
+/// Parses a leaf line such as `.*$..*$***$`, or a branch line such as `4 1 0 0 2` whose children
+/// must be earlier nodes one level down.
+fn parse_node(line: &str, nodes: &[Option<Node>]) -> Option<Node> {
+    if line.starts_with(|char: char| char.is_ascii_digit()) {
+        let mut fields = line
+            .split_whitespace()
+            .map(|field| field.parse::<usize>().ok());
+        let level = fields.next()?? as u32;
+        if level <= LEAF_LEVEL || level > 63 {
+            return None;
+        }
+        let mut children = [0; 4];
+        for child in &mut children {
+            *child = fields.next()??;
+            let child_level = match nodes.get(*child)? {
+                None => level - 1,
+                Some(Node::Leaf(_)) => LEAF_LEVEL,
+                Some(Node::Branch { level, .. }) => *level,
+            };
+            if child_level != level - 1 {
+                return None;
+            }
+        }
+        if fields.next().is_some() {
+            return None;
+        }
+        return Some(Node::Branch { level, children });
+    }
+    let mut rows = [0; 8];
+    let mut row = 0;
+    let mut col = 0;
+    for char in line.chars() {
+        match char {
+            '$' => {
+                row += 1;
+                col = 0;
+            }
+            '.' | '*' if row < 8 && col < 8 => {
+                if char == '*' {
+                    rows[row] |= 1 << col;
+                }
+                col += 1;
+            }
+            _ => return None,
+        }
+    }
+    Some(Node::Leaf(rows))
+}
+
+fn population(node: &Node, populations: &[u64]) -> u64 {
+    match node {
+        Node::Leaf(rows) => rows.iter().map(|row| row.count_ones() as u64).sum(),
+        Node::Branch { children, .. } => children.iter().fold(0, |population: u64, &child| {
+            population.saturating_add(populations[child])
+        }),
+    }
+}
+
+/// Adds the coordinates of the live cells of node `index`, whose top-left corner is at `at`.
+fn collect_live(
+    nodes: &[Option<Node>],
+    populations: &[u64],
+    index: usize,
+    at: (u64, u64),
+    cells: &mut Vec<(u64, u64)>,
+) {
+    if populations[index] == 0 {
+        return;
+    }
+    let (top, left) = at;
+    match &nodes[index] {
+        None => {}
+        Some(Node::Leaf(rows)) => {
+            for (row, bits) in (0..).zip(rows) {
+                for col in (0..8).filter(|col| bits & 1 << col != 0) {
+                    cells.push((top + row, left + col));
+                }
+            }
+        }
+        Some(Node::Branch { level, children }) => {
+            let half = 1 << (level - 1);
+            for (quadrant, &child) in (0..).zip(children) {
+                let at = (top + quadrant / 2 * half, left + quadrant % 2 * half);
+                collect_live(nodes, populations, child, at, cells);
+            }
+        }
+    }
+}
+
+/// Returned when macrocell input cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MacrocellError {
+    /// The input did not start with `[M2]`.
+    MissingHeader,
+    /// A line was neither a valid leaf nor a branch whose children are earlier nodes one level
+    /// below it.
+    InvalidLine { line: usize },
+    /// The `#R` rule could not be parsed.
+    InvalidRule(RuleError),
+    /// The pattern did not fit within the requested bounds.
+    ExceedsBounds,
+}
+
+impl fmt::Display for MacrocellError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MacrocellError::MissingHeader => write!(f, "missing {:?} header", HEADER),
+            MacrocellError::InvalidLine { line } => {
+                write!(f, "line {}: invalid macrocell node", line)
+            }
+            MacrocellError::InvalidRule(err) => write!(f, "invalid macrocell rule: {}", err),
+            MacrocellError::ExceedsBounds => f.write_str("pattern is larger than the board"),
+        }
+    }
+}
+
+impl Error for MacrocellError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MacrocellError::InvalidRule(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_macrocell_leaf() {
+        let macrocell = "[M2] (golly 4.2)\n#R B36/S23\n#C a glider\n$$..*$...*$.***$\n";
+        let glider = GameOfLife::from_macrocell(macrocell, (4, 3)).unwrap();
+        #[rustfmt::skip]
+        assert_eq!(glider, GameOfLife::from_lines(&[
+            " • ",
+            "  •",
+            "•••",
+            "   ",
+        ]).with_rule(Rule::parse("B36/S23").unwrap()));
+    }
+
+    #[test]
+    fn test_from_macrocell_expands_branches() {
+        // a level 4 node holding a block and a blinker, used twice in the level 5 node, and
+        // another holding only the blinker
+        let macrocell = "[M2]
+$$$$$$......**$......**$
+***$
+4 1 0 0 2
+4 0 0 2 0
+5 3 0 4 3
+";
+        let game = GameOfLife::from_macrocell(macrocell, (19, 27)).unwrap();
+        let expected = vec![
+            (0, 6),
+            (0, 7),
+            (1, 6),
+            (1, 7),
+            (2, 8),
+            (2, 9),
+            (2, 10),
+            (16, 22),
+            (16, 23),
+            (17, 22),
+            (17, 23),
+            (18, 0),
+            (18, 1),
+            (18, 2),
+            (18, 24),
+            (18, 25),
+            (18, 26),
+        ];
+        assert_eq!(game.live_cells(), expected);
+        assert_eq!(
+            GameOfLife::from_macrocell(macrocell, (18, 27)),
+            Err(MacrocellError::ExceedsBounds)
+        );
+        assert_eq!(
+            GameOfLife::from_macrocell(macrocell, (19, 26)),
+            Err(MacrocellError::ExceedsBounds)
+        );
+        assert_eq!(
+            GameOfLife::from_macrocell(macrocell, (4, 4)),
+            Err(MacrocellError::ExceedsBounds)
+        );
+    }
+
+    #[test]
+    fn test_from_macrocell_errors() {
+        assert_eq!(
+            GameOfLife::from_macrocell("#Life 1.06\n", (1, 1)),
+            Err(MacrocellError::MissingHeader)
+        );
+        for (input, line) in &[
+            ("[M2]\n*x*$\n", 2),
+            ("[M2]\n*********$\n", 2),
+            ("[M2]\n$$$$$$$$*\n", 2),
+            ("[M2]\n*\n4 1 0 0 2\n", 3),
+            ("[M2]\n*\n5 1 0 0 0\n", 3),
+            ("[M2]\n*\n3 1 1 1 1\n", 3),
+            ("[M2]\n*\n4 1 1 1\n", 3),
+            ("[M2]\n*\n4 1 1 1 1 1\n", 3),
+        ] {
+            assert_eq!(
+                GameOfLife::from_macrocell(input, (100, 100)),
+                Err(MacrocellError::InvalidLine { line: *line }),
+                "{:?}",
+                input
+            );
+        }
+        assert_eq!(
+            GameOfLife::from_macrocell("[M2]\n#R B9/S\n", (1, 1)),
+            Err(MacrocellError::InvalidRule(RuleError::CountOutOfRange(9)))
+        );
+    }
+
+    #[test]
+    fn test_from_macrocell_rejects_huge_patterns_quickly() {
+        // a 2^63 wide square, full of copies of a single live cell
+        let mut macrocell = String::from("[M2]\n*\n");
+        macrocell.push_str("4 1 1 1 1\n");
+        for level in 5..=63 {
+            let below = level - 3;
+            macrocell.push_str(&format!(
+                "{} {} {} {} {}\n",
+                level, below, below, below, below
+            ));
+        }
+        assert_eq!(
+            GameOfLife::from_macrocell(&macrocell, (1000, 1000)),
+            Err(MacrocellError::ExceedsBounds)
+        );
+    }
+}
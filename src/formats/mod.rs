@@ -0,0 +1,33 @@
+use alloc::vec::Vec;
+use core::mem;
+
+mod checkpoint;
+#[cfg(feature = "std")]
+mod file;
+mod json;
+mod life106;
+mod macrocell;
+mod plaintext;
+mod rle;
+
+pub use checkpoint::CheckpointError;
+pub use json::JsonError;
+pub use life106::Life106Error;
+pub use macrocell::MacrocellError;
+pub use rle::RleError;
+
+/// The most memory, in bytes, that a board read from a pattern file or checkpoint may take: a
+/// byte for each cell plus the `Vec` holding each row.
+///
+/// Larger dimensions are rejected before anything is allocated, so an absurd header is an error
+/// rather than an abort, whatever the host's memory or overcommit policy.
+pub const MAX_BOARD_BYTES: usize = 1 << 30;
+
+/// Whether a `rows` by `cols` board takes no more than [`MAX_BOARD_BYTES`].
+pub(crate) fn within_size_limit(rows: usize, cols: usize) -> bool {
+    let bytes = rows.checked_mul(cols).and_then(|cells| {
+        rows.checked_mul(mem::size_of::<Vec<bool>>())
+            .and_then(|rows| rows.checked_add(cells))
+    });
+    bytes.is_some_and(|bytes| bytes <= MAX_BOARD_BYTES)
+}
@@ -0,0 +1,57 @@
+//! The plaintext `.cells` format, described at <https://conwaylife.com/wiki/Plaintext>.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::GameOfLife;
+
+impl GameOfLife {
+    /// Parses a pattern in plaintext format, where `O` is a live cell and `.` a dead one.
+    ///
+    /// Lines starting with `!` are comments. Rows shorter than the widest are padded with dead
+    /// cells, and, as some older files use it, `*` is also accepted as a live cell.
+    pub fn from_plaintext(input: &str) -> GameOfLife {
+        let lines: Vec<&str> = input
+            .lines()
+            .filter(|line| !line.starts_with('!'))
+            .collect();
+        GameOfLife::parse_lines(&lines, |char| char == 'O' || char == '*')
+    }
+
+    /// Writes this board in plaintext format, one line of `O` and `.` per row.
+    pub fn to_plaintext(&self) -> String {
+        self.cells()
+            .iter()
+            .map(|row| {
+                let mut line: String = row
+                    .iter()
+                    .map(|&alive| if alive { 'O' } else { '.' })
+                    .collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::GameOfLife;
+
+    #[test]
+    fn test_plaintext_round_trips() {
+        let plaintext = "!Name: Glider\n!\n.O.\n..O\nOOO\n";
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            " • ",
+            "  •",
+            "•••",
+        ]);
+        assert_eq!(GameOfLife::from_plaintext(plaintext), glider);
+        assert_eq!(glider.to_plaintext(), ".O.\n..O\nOOO\n");
+        assert_eq!(
+            GameOfLife::from_plaintext(".O\n..*\n"),
+            GameOfLife::from_lines(&[" • ", "  •"])
+        );
+    }
+}
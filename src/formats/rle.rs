@@ -0,0 +1,455 @@
+//! The Run Length Encoded format, described at <https://conwaylife.com/wiki/Run_Length_Encoded>.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::mem;
+
+use crate::{GameOfLife, Rule, RuleError};
+
+impl GameOfLife {
+    /// Parses a pattern in RLE format.
+    ///
+    /// Lines starting with `#` are comments. The first other line is the `x = .., y = ..` header,
+    /// optionally followed by `rule = ..` in either `B3/S23` or the older `23/3` notation, which
+    /// sets the board's rule. The pattern that follows uses `b` for dead cells, `o` for live cells,
+    /// `$` to end a row and `!` to end the pattern, each optionally preceded by a run count. Rows
+    /// and cells the pattern leaves out are dead, but a pattern extending beyond the header's
+    /// dimensions is an error, as are dimensions whose board would take more than
+    /// [`MAX_BOARD_BYTES`](crate::MAX_BOARD_BYTES).
+    pub fn from_rle(input: &str) -> Result<GameOfLife, RleError> {
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+        let (line_num, header) = lines.next().ok_or(RleError::MissingHeader)?;
+        let header = Header::parse(line_num, header)?;
+        header.check_size()?;
+
+        let mut decoder = Decoder::new(&header);
+        for (line_num, line) in lines {
+            if decoder.decode_line(line_num, line)? {
+                return Ok(GameOfLife::from_cells(decoder.finish()).with_rule(header.rule));
+            }
+        }
+        Err(RleError::Unterminated)
+    }
+
+    /// Writes this board in RLE format, with a header giving its dimensions and rule.
+    ///
+    /// Dead cells at the end of a row and empty rows at the end of the board are left out, as the
+    /// format allows, and pattern lines are wrapped to at most 70 characters.
+    pub fn to_rle(&self) -> String {
+        let (rows, cols) = self.dimensions();
+        let mut encoder = Encoder::default();
+        let mut pending_row_ends = 0;
+        for row in self.cells() {
+            let live_len = row
+                .iter()
+                .rposition(|&alive| alive)
+                .map_or(0, |col| col + 1);
+            if live_len == 0 {
+                pending_row_ends += 1;
+                continue;
+            }
+            encoder.run(pending_row_ends, '$');
+            let mut cells = row[..live_len].iter().peekable();
+            while let Some(&alive) = cells.next() {
+                let mut run_count = 1;
+                while cells.next_if(|&&next| next == alive).is_some() {
+                    run_count += 1;
+                }
+                encoder.run(run_count, if alive { 'o' } else { 'b' });
+            }
+            pending_row_ends = 1;
+        }
+        encoder.run(1, '!');
+        format!(
+            "x = {}, y = {}, rule = {}\n{}\n",
+            cols,
+            rows,
+            self.rule(),
+            encoder.lines.join("\n")
+        )
+    }
+}
+
+const MAX_LINE_LEN: usize = 70;
+
+#[derive(Default)]
+struct Encoder {
+    lines: Vec<String>,
+}
+
+impl Encoder {
+    fn run(&mut self, run_count: usize, tag: char) {
+        let token = match run_count {
+            0 => return,
+            1 => tag.to_string(),
+            _ => format!("{}{}", run_count, tag),
+        };
+        match self.lines.last_mut() {
+            Some(line) if line.len() + token.len() <= MAX_LINE_LEN => line.push_str(&token),
+            _ => self.lines.push(token),
+        }
+    }
+}
+
+struct Header {
+    width: usize,
+    height: usize,
+    rule: Rule,
+}
+
+impl Header {
+    fn parse(line_num: usize, line: &str) -> Result<Header, RleError> {
+        let invalid = RleError::InvalidHeader { line: line_num };
+        let (mut width, mut height, mut rule) = (None, None, Rule::default());
+        for field in line.split(',') {
+            let mut key_value = field.splitn(2, '=').map(str::trim);
+            let key = key_value.next().ok_or_else(|| invalid.clone())?;
+            let value = key_value.next().ok_or_else(|| invalid.clone())?;
+            match key {
+                "x" => width = Some(value.parse().map_err(|_| invalid.clone())?),
+                "y" => height = Some(value.parse().map_err(|_| invalid.clone())?),
+                "rule" => rule = parse_rule(value).map_err(RleError::InvalidRule)?,
+                _ => {}
+            }
+        }
+        match (width, height) {
+            (Some(width), Some(height)) => Ok(Header {
+                width,
+                height,
+                rule,
+            }),
+            _ => Err(invalid),
+        }
+    }
+}
+
+/// Parses a header's rule, which older files give in `S/B` notation without prefixes, so that
+/// Conway's rule is `23/3`.
+fn parse_rule(value: &str) -> Result<Rule, RuleError> {
+    match value.split_once('/') {
+        Some((survival, birth))
+            if survival
+                .chars()
+                .chain(birth.chars())
+                .all(|char| char.is_ascii_digit()) =>
+        {
+            Rule::parse(&format!("B{}/S{}", birth, survival))
+        }
+        _ => Rule::parse(value),
+    }
+}
+
+impl Header {
+    /// Checks that a board of the declared size is within
+    /// [`MAX_BOARD_BYTES`](crate::MAX_BOARD_BYTES), so that an absurd header is an error rather
+    /// than an abort once decoding runs out of memory.
+    fn check_size(&self) -> Result<(), RleError> {
+        if super::within_size_limit(self.height, self.width) {
+            Ok(())
+        } else {
+            Err(RleError::TooLarge {
+                width: self.width,
+                height: self.height,
+            })
+        }
+    }
+}
+
+struct Decoder {
+    width: usize,
+    height: usize,
+    rows: Vec<Vec<bool>>,
+    row: Vec<bool>,
+    run_count: Option<usize>,
+}
+
+impl Decoder {
+    fn new(header: &Header) -> Decoder {
+        Decoder {
+            width: header.width,
+            height: header.height,
+            rows: Vec::new(),
+            row: Vec::new(),
+            run_count: None,
+        }
+    }
+
+    /// Decodes one line of pattern data, returning whether it contained the closing `!`.
+    fn decode_line(&mut self, line_num: usize, line: &str) -> Result<bool, RleError> {
+        for char in line.chars() {
+            if let Some(digit) = char.to_digit(10) {
+                self.run_count = self
+                    .run_count
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|count| count.checked_add(digit as usize));
+                if self.run_count.is_none() {
+                    return Err(RleError::InvalidRunCount { line: line_num });
+                }
+                continue;
+            }
+            let run_count = self.run_count.take().unwrap_or(1);
+            match char {
+                'b' | 'o' => {
+                    if self.row.len().saturating_add(run_count) > self.width {
+                        return Err(self.exceeds_header());
+                    }
+                    let alive = char == 'o';
+                    self.row.extend((0..run_count).map(|_| alive));
+                }
+                '$' => self.end_rows(run_count)?,
+                '!' => {
+                    if !self.row.is_empty() {
+                        self.end_rows(1)?;
+                    }
+                    return Ok(true);
+                }
+                char if char.is_whitespace() => {}
+                char => {
+                    return Err(RleError::UnexpectedChar {
+                        line: line_num,
+                        char,
+                    })
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// Ends the current row and `count - 1` blank rows after it; a count of 0 does nothing.
+    fn end_rows(&mut self, count: usize) -> Result<(), RleError> {
+        if count == 0 {
+            return Ok(());
+        }
+        if self.rows.len().saturating_add(count) > self.height {
+            return Err(self.exceeds_header());
+        }
+        self.rows.push(mem::take(&mut self.row));
+        self.rows.extend((1..count).map(|_| Vec::new()));
+        Ok(())
+    }
+
+    fn exceeds_header(&self) -> RleError {
+        RleError::ExceedsHeader {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn finish(mut self) -> Vec<Vec<bool>> {
+        self.rows.resize_with(self.height, Vec::new);
+        for row in &mut self.rows {
+            row.resize(self.width, false);
+        }
+        self.rows
+    }
+}
+
+/// Returned when RLE input cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RleError {
+    /// The input had no header line.
+    MissingHeader,
+    /// The header line was not of the form `x = .., y = ..`.
+    InvalidHeader { line: usize },
+    /// The header's `rule` could not be parsed.
+    InvalidRule(RuleError),
+    /// A run count was too large to represent.
+    InvalidRunCount { line: usize },
+    /// The pattern data contained something other than a run count, `b`, `o`, `$` or `!`.
+    UnexpectedChar { line: usize, char: char },
+    /// The pattern extended beyond the `width` or `height` declared in its header.
+    ExceedsHeader { width: usize, height: usize },
+    /// The input ended without the closing `!`.
+    Unterminated,
+    /// The `width` and `height` declared in the header give a board larger than
+    /// [`MAX_BOARD_BYTES`](crate::MAX_BOARD_BYTES).
+    TooLarge { width: usize, height: usize },
+}
+
+impl fmt::Display for RleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RleError::MissingHeader => f.write_str("missing RLE header"),
+            RleError::InvalidHeader { line } => write!(f, "line {}: invalid RLE header", line),
+            RleError::InvalidRule(err) => write!(f, "invalid rule in RLE header: {}", err),
+            RleError::InvalidRunCount { line } => write!(f, "line {}: run count too large", line),
+            RleError::UnexpectedChar { line, char } => {
+                write!(f, "line {}: unexpected character {:?}", line, char)
+            }
+            RleError::ExceedsHeader { width, height } => write!(
+                f,
+                "pattern is larger than the {}x{} declared in its header",
+                width, height
+            ),
+            RleError::Unterminated => f.write_str("RLE pattern is missing its closing '!'"),
+            RleError::TooLarge { width, height } => {
+                write!(f, "a {}x{} board is too large to load", width, height)
+            }
+        }
+    }
+}
+
+impl Error for RleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RleError::InvalidRule(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_rle_glider() {
+        let rle =
+            "#N Glider\n#C The smallest spaceship.\nx = 3, y = 3, rule = B3/S23\nbob$2bo$3o!\n";
+        #[rustfmt::skip]
+        assert_eq!(
+            GameOfLife::from_rle(rle),
+            Ok(GameOfLife::from_lines(&[
+                " • ",
+                "  •",
+                "•••",
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_from_rle_fills_omitted_cells_and_rows() {
+        let rle = "x = 4, y = 4, rule = B36/S23\n2o\n2$\n3bo$!";
+        #[rustfmt::skip]
+        let expected = GameOfLife::from_lines(&[
+            "••  ",
+            "    ",
+            "   •",
+            "    ",
+        ])
+        .with_rule(Rule::parse("B36/S23").unwrap());
+        assert_eq!(GameOfLife::from_rle(rle), Ok(expected));
+    }
+
+    #[test]
+    fn test_from_rle_skips_zero_runs() {
+        assert_eq!(
+            GameOfLife::from_rle("x = 2, y = 2\n0o0b2o0$$o0$!"),
+            Ok(GameOfLife::from_lines(&["••", "• "]))
+        );
+    }
+
+    #[test]
+    fn test_from_rle_reads_sb_rules() {
+        let highlife = GameOfLife::from_rle("x = 1, y = 1, rule = 23/36\no!").unwrap();
+        assert_eq!(highlife.rule(), Rule::parse("B36/S23").unwrap());
+        let seeds = GameOfLife::from_rle("x = 1, y = 1, rule = /2\no!").unwrap();
+        assert_eq!(seeds.rule(), Rule::parse("B2/S").unwrap());
+        assert_eq!(
+            GameOfLife::from_rle("x = 1, y = 1, rule = 9/3\no!"),
+            Err(RleError::InvalidRule(RuleError::CountOutOfRange(9)))
+        );
+    }
+
+    #[test]
+    fn test_to_rle_glider_is_stable() {
+        let rle = "x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!\n";
+        let glider = GameOfLife::from_rle(rle).unwrap();
+        assert_eq!(glider.to_rle(), rle);
+        assert_eq!(GameOfLife::from_rle(&glider.to_rle()), Ok(glider));
+    }
+
+    #[test]
+    fn test_to_rle_collapses_empty_rows() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            "     ",
+            "•• • ",
+            "     ",
+            "     ",
+            "   ••",
+            "     ",
+        ]);
+        assert_eq!(game.to_rle(), "x = 5, y = 6, rule = B3/S23\n$2obo3$3b2o!\n");
+        assert_eq!(GameOfLife::from_rle(&game.to_rle()), Ok(game));
+    }
+
+    #[test]
+    fn test_to_rle_wraps_long_lines() {
+        let game = GameOfLife::random(40, 40, 0.5, &mut crate::SplitMix64::new(1));
+        let rle = game.to_rle();
+        assert!(rle.lines().all(|line| line.len() <= MAX_LINE_LEN));
+        assert_eq!(GameOfLife::from_rle(&rle), Ok(game));
+    }
+
+    #[test]
+    fn test_from_rle_errors() {
+        assert_eq!(
+            GameOfLife::from_rle("#C nothing"),
+            Err(RleError::MissingHeader)
+        );
+        assert_eq!(
+            GameOfLife::from_rle("x = 3\nbob!"),
+            Err(RleError::InvalidHeader { line: 1 })
+        );
+        assert_eq!(
+            GameOfLife::from_rle("x = 3, y = 3, rule = B9/S\nbob!"),
+            Err(RleError::InvalidRule(RuleError::CountOutOfRange(9)))
+        );
+        assert_eq!(
+            GameOfLife::from_rle("x = 3, y = 3\nbob$2bo"),
+            Err(RleError::Unterminated)
+        );
+        assert_eq!(
+            GameOfLife::from_rle("x = 3, y = 3\nbxb!"),
+            Err(RleError::UnexpectedChar { line: 2, char: 'x' })
+        );
+        assert_eq!(
+            GameOfLife::from_rle("x = 2, y = 2\n3o!"),
+            Err(RleError::ExceedsHeader {
+                width: 2,
+                height: 2
+            })
+        );
+        assert_eq!(
+            GameOfLife::from_rle("x = 2, y = 2\no$o$o!"),
+            Err(RleError::ExceedsHeader {
+                width: 2,
+                height: 2
+            })
+        );
+        assert_eq!(
+            GameOfLife::from_rle("x = 1, y = 1\no$0$o!"),
+            Err(RleError::ExceedsHeader {
+                width: 1,
+                height: 1
+            })
+        );
+        assert_eq!(
+            GameOfLife::from_rle("x = 2, y = 2\n99999999999999999999999o!"),
+            Err(RleError::InvalidRunCount { line: 2 })
+        );
+        assert_eq!(
+            GameOfLife::from_rle("x = 18446744073709551615, y = 2\n!"),
+            Err(RleError::TooLarge {
+                width: usize::MAX,
+                height: 2
+            })
+        );
+        assert_eq!(
+            GameOfLife::from_rle("x = 0, y = 1000000000000000000\n!"),
+            Err(RleError::TooLarge {
+                width: 0,
+                height: 1_000_000_000_000_000_000
+            })
+        );
+    }
+}
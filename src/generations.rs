@@ -0,0 +1,217 @@
+use alloc::vec::Vec;
+use core::mem;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+use crate::GameOfLife;
+
+/// An endless iterator over a board and each of its successive generations.
+///
+/// Created by [`GameOfLife::generations`].
+#[derive(Debug)]
+pub struct Generations {
+    current: GameOfLife,
+}
+
+impl GameOfLife {
+    /// Iterates over this board followed by every subsequent generation.
+    pub fn generations(self) -> Generations {
+        Generations { current: self }
+    }
+
+    /// Iterates over this board followed by every `stride`th subsequent generation, so a stride of
+    /// 1 yields every generation, as [`GameOfLife::generations`] does.
+    ///
+    /// A stride of 0 is treated as 1.
+    pub fn generations_stepped(self, stride: usize) -> impl Iterator<Item = GameOfLife> {
+        self.generations().step_by(stride.max(1))
+    }
+
+    /// Computes the board `n` generations from now; `n == 0` returns a copy of this board.
+    ///
+    /// Only the final board is kept, with a single copy of this one stepped in place.
+    pub fn nth_generation(&self, n: usize) -> GameOfLife {
+        let mut game = self.clone();
+        game.advance(n);
+        game
+    }
+
+    /// Iterates over this board and its subsequent generations until one repeats an earlier
+    /// generation, yielding at most `max` boards.
+    ///
+    /// Every generation is yielded once, so a still life yields only itself and an oscillator
+    /// yields each of its phases.
+    #[cfg(feature = "std")]
+    pub fn generations_until_stable(self, max: usize) -> impl Iterator<Item = GameOfLife> {
+        let mut seen = HashSet::new();
+        self.generations()
+            .take(max)
+            .take_while(move |game| seen.insert(game.clone()))
+    }
+
+    /// This board followed by its next `generations` generations, `generations + 1` boards in all.
+    pub fn run_collect(&self, generations: usize) -> Vec<GameOfLife> {
+        self.clone().generations().take(generations + 1).collect()
+    }
+
+    /// Steps this board `generations` times, calling `on_step` with each new generation and its
+    /// number, from 1 up to `generations`.
+    ///
+    /// The initial board, generation 0, is not passed to `on_step`.
+    pub fn run(&self, generations: usize, mut on_step: impl FnMut(usize, &GameOfLife)) {
+        let mut game = self.clone();
+        for generation in 1..=generations {
+            game = game.next_state();
+            on_step(generation, &game);
+        }
+    }
+}
+
+impl Iterator for Generations {
+    type Item = GameOfLife;
+
+    fn next(&mut self) -> Option<GameOfLife> {
+        let next = self.current.next_state();
+        Some(mem::replace(&mut self.current, next))
+    }
+
+    /// Skips ahead by stepping in place, without making a board for each skipped generation.
+    fn nth(&mut self, n: usize) -> Option<GameOfLife> {
+        self.current.advance(n);
+        self.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::GameOfLife;
+
+    #[test]
+    fn test_generations_starts_with_initial_state() {
+        #[rustfmt::skip]
+        let vertical = GameOfLife::from_lines(&[
+            "   ",
+            " • ",
+            " • ",
+            " • ",
+            "   ",
+        ]);
+        #[rustfmt::skip]
+        let horizontal = GameOfLife::from_lines(&[
+            "   ",
+            "   ",
+            "•••",
+            "   ",
+            "   ",
+        ]);
+        let mut generations = vertical.next_state().next_state().generations();
+        assert_eq!(generations.next(), Some(vertical));
+        assert_eq!(generations.next(), Some(horizontal));
+    }
+
+    #[test]
+    fn test_nth_generation() {
+        #[rustfmt::skip]
+        let vertical = GameOfLife::from_lines(&[
+            "   ",
+            " • ",
+            " • ",
+            " • ",
+            "   ",
+        ]);
+        assert_eq!(vertical.nth_generation(0), vertical);
+        assert_eq!(vertical.nth_generation(1), vertical.next_state());
+        assert_eq!(vertical.nth_generation(2), vertical);
+        assert_eq!(vertical.nth_generation(7), vertical.next_state());
+    }
+
+    #[test]
+    fn test_run_calls_back_after_each_step() {
+        let blinker = GameOfLife::from_lines(&["   ", "•••", "   "]);
+        let mut steps = Vec::new();
+        blinker.run(3, |generation, game| steps.push((generation, game.clone())));
+        assert_eq!(
+            steps,
+            vec![
+                (1, blinker.next_state()),
+                (2, blinker.clone()),
+                (3, blinker.next_state()),
+            ]
+        );
+
+        blinker.run(0, |_, _| panic!("no steps were asked for"));
+    }
+
+    #[test]
+    fn test_generations_until_stable() {
+        let block = GameOfLife::from_lines(&["••", "••"]);
+        assert_eq!(
+            block
+                .clone()
+                .generations_until_stable(10)
+                .collect::<Vec<_>>(),
+            vec![block]
+        );
+
+        let blinker = GameOfLife::from_lines(&["   ", "•••", "   "]);
+        assert_eq!(blinker.clone().generations_until_stable(10).count(), 2);
+        assert_eq!(blinker.generations_until_stable(1).count(), 1);
+
+        // the diagonal shrinks for two generations and then stays empty
+        let diagonal = GameOfLife::from_lines(&["•   ", " •  ", "  • ", "   •"]);
+        let populations: Vec<usize> = diagonal
+            .generations_until_stable(10)
+            .map(|game| game.population())
+            .collect();
+        assert_eq!(populations, vec![4, 2, 0]);
+    }
+
+    #[test]
+    fn test_run_collect_includes_initial_board() {
+        let blinker = GameOfLife::from_lines(&["   ", "•••", "   "]);
+        let flipped = blinker.next_state();
+        assert_eq!(blinker.run_collect(0), vec![blinker.clone()]);
+        assert_eq!(
+            blinker.run_collect(3),
+            vec![blinker.clone(), flipped.clone(), blinker, flipped]
+        );
+    }
+
+    #[test]
+    fn test_generations_stepped() {
+        let glider = crate::patterns::glider().padded(4);
+        let stepped: Vec<_> = glider.clone().generations_stepped(4).take(3).collect();
+        assert_eq!(
+            stepped,
+            vec![
+                glider.clone(),
+                glider.nth_generation(4),
+                glider.nth_generation(8)
+            ]
+        );
+        assert!(glider
+            .clone()
+            .generations_stepped(0)
+            .take(5)
+            .eq(glider.clone().generations().take(5)));
+        assert!(glider
+            .clone()
+            .generations_stepped(1)
+            .take(5)
+            .eq(glider.generations().take(5)));
+    }
+
+    #[test]
+    fn test_nth_skips_ahead() {
+        let glider = crate::patterns::glider().padded(4);
+        let mut generations = glider.clone().generations();
+        assert_eq!(generations.nth(1), Some(glider.nth_generation(1)));
+        assert_eq!(generations.nth(2), Some(glider.nth_generation(4)));
+        assert_eq!(generations.next(), Some(glider.nth_generation(5)));
+        assert_eq!(
+            glider.clone().generations().nth(7),
+            glider.clone().generations().take(8).last()
+        );
+        assert_eq!(glider.nth_generation(0), glider);
+    }
+}
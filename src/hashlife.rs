@@ -0,0 +1,546 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::{B0RuleError, GameOfLife, Rule};
+
+/// An index into [`HashLife`]'s table of nodes.
+type NodeId = usize;
+
+/// The two nodes at level 0, which are single cells.
+const DEAD: NodeId = 0;
+const ALIVE: NodeId = 1;
+
+/// The deepest tree whose coordinates still fit in an `i64`.
+const MAX_LEVEL: u32 = 62;
+
+/// A square of `2^level` by `2^level` cells, split into four quadrants of the level below.
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    level: u32,
+    /// The north-west, north-east, south-west and south-east quadrants; unused at level 0.
+    children: [NodeId; 4],
+    population: u64,
+}
+
+/// An unbounded board stored as a quadtree of shared, memoized squares, after Bill Gosper's
+/// HashLife algorithm.
+///
+/// Identical squares are stored once however often they appear, and the future of each square
+/// is computed once and then remembered, so patterns with a lot of repetition in space or time
+/// can jump ahead by huge numbers of generations. Coordinates are `(row, col)` and may be
+/// negative, as on a [`SparseLife`](crate::SparseLife).
+///
+/// Nothing is forgotten until [`compact`](HashLife::compact) is called, so until then memory use
+/// only grows as the board evolves.
+#[derive(Debug, Clone)]
+pub struct HashLife {
+    nodes: Vec<Node>,
+    /// Finds the existing node, if any, with the given children.
+    ids: HashMap<[NodeId; 4], NodeId>,
+    /// `empty[level]` is the node at that level with no live cells.
+    empty: Vec<NodeId>,
+    /// `results[(node, k)]` is the center of `node` after `2^k` generations.
+    results: HashMap<(NodeId, u32), NodeId>,
+    /// The whole board, centered on the origin; everything outside it is dead.
+    root: NodeId,
+    generation: u64,
+    rule: Rule,
+}
+
+impl HashLife {
+    /// Creates an empty board that evolves under Conway's rule.
+    pub fn new() -> HashLife {
+        let leaf = |population| Node {
+            level: 0,
+            children: [DEAD; 4],
+            population,
+        };
+        let mut game = HashLife {
+            nodes: vec![leaf(0), leaf(1)],
+            ids: HashMap::new(),
+            empty: vec![DEAD],
+            results: HashMap::new(),
+            root: DEAD,
+            generation: 0,
+            rule: Rule::conway(),
+        };
+        game.root = game.empty(3);
+        game
+    }
+
+    /// Returns this board evolving under `rule` instead of its current rule.
+    ///
+    /// Only the Moore neighborhood is supported, and rules with births on 0 neighbors are an
+    /// error, since the empty squares around the pattern are assumed to stay empty.
+    pub fn with_rule(mut self, rule: Rule) -> Result<HashLife, B0RuleError> {
+        B0RuleError::check(rule)?;
+        if rule != self.rule {
+            self.results.clear();
+            self.rule = rule;
+        }
+        Ok(self)
+    }
+
+    /// The rule this board evolves under.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// How many generations this board has been advanced by.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// The number of living cells.
+    pub fn population(&self) -> u64 {
+        self.nodes[self.root].population
+    }
+
+    /// Whether the cell at `row`, `col` is alive.
+    pub fn is_alive(&self, row: i64, col: i64) -> bool {
+        let mut level = self.nodes[self.root].level;
+        let half = 1i64 << (level - 1);
+        if !(-half..half).contains(&row) || !(-half..half).contains(&col) {
+            return false;
+        }
+        let (mut row, mut col) = ((row + half) as u64, (col + half) as u64);
+        let mut id = self.root;
+        while level > 0 && self.nodes[id].population > 0 {
+            level -= 1;
+            let half = 1 << level;
+            id = self.nodes[id].children[quadrant(row >= half, col >= half)];
+            row %= half;
+            col %= half;
+        }
+        id == ALIVE
+    }
+
+    /// Sets whether the cell at `row`, `col` is alive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the board would need to be more than `2^62` cells across to hold the cell.
+    pub fn set(&mut self, row: i64, col: i64, alive: bool) {
+        loop {
+            let half = 1i64 << (self.nodes[self.root].level - 1);
+            if (-half..half).contains(&row) && (-half..half).contains(&col) {
+                let root = self.root;
+                let level = self.nodes[root].level;
+                self.root =
+                    self.set_in(root, level, (row + half) as u64, (col + half) as u64, alive);
+                return;
+            }
+            self.expand();
+        }
+    }
+
+    /// The `(row, col)` coordinates of every live cell, in no particular order.
+    pub fn live_cells(&self) -> Vec<(i64, i64)> {
+        let mut cells = Vec::new();
+        let half = 1i64 << (self.nodes[self.root].level - 1);
+        self.collect_live(self.root, -half, -half, &mut cells);
+        cells
+    }
+
+    /// Advances this board by `2^k` generations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the board would need to be more than `2^62` cells across to hold everything
+    /// that could happen in that time, including whenever `k` is 60 or more.
+    pub fn advance_pow2(&mut self, k: u32) {
+        // the result is the central half of the root, which holds the whole pattern as long as
+        // it starts in the central quarter and the root is wide enough that it can't grow out
+        loop {
+            let root = self.nodes[self.root];
+            let inner = self.center(self.root);
+            let inner = self.center(inner);
+            if root.level >= k + 3 && self.nodes[inner].population == root.population {
+                break;
+            }
+            self.expand();
+        }
+        self.root = self.future(self.root, k);
+        self.generation += 1 << k;
+    }
+
+    /// Advances this board by `generations` generations, as a sum of powers of two.
+    ///
+    /// # Panics
+    ///
+    /// Panics as [`advance_pow2`](HashLife::advance_pow2) does for the largest of those powers.
+    pub fn advance(&mut self, generations: u64) {
+        for k in 0..u64::BITS {
+            if generations & 1 << k != 0 {
+                self.advance_pow2(k);
+            }
+        }
+    }
+
+    /// Forgets every square that is no longer part of the board, along with every remembered
+    /// future, to free the memory a long run has built up.
+    ///
+    /// The board is unchanged, but futures have to be computed again, so compacting too often
+    /// throws away the memoization that makes HashLife fast.
+    pub fn compact(&mut self) {
+        let mut compacted = HashLife {
+            nodes: self.nodes[..=ALIVE].to_vec(),
+            ids: HashMap::new(),
+            empty: vec![DEAD],
+            results: HashMap::new(),
+            root: DEAD,
+            generation: self.generation,
+            rule: self.rule,
+        };
+        compacted.root = compacted.copy_from(self, self.root, &mut HashMap::new());
+        *self = compacted;
+    }
+
+    /// Copies the cells with rows in `0..rows` and columns in `0..cols` onto a dense board of
+    /// `bounds`, given as `(rows, cols)`; live cells outside it are dropped.
+    pub fn to_dense(&self, bounds: (usize, usize)) -> GameOfLife {
+        let (rows, cols) = bounds;
+        let mut game = GameOfLife::dead(rows, cols).with_rule(self.rule);
+        for (row, col) in self.live_cells() {
+            if row >= 0 && col >= 0 {
+                // cells beyond the bounds are dropped, as documented
+                let _ = game.set(row as usize, col as usize, true);
+            }
+        }
+        game
+    }
+
+    /// The node with the given quadrants, which must all be at the same level.
+    fn node(&mut self, children: [NodeId; 4]) -> NodeId {
+        if let Some(&id) = self.ids.get(&children) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            level: self.nodes[children[0]].level + 1,
+            children,
+            population: children
+                .iter()
+                .map(|&child| self.nodes[child].population)
+                .sum(),
+        });
+        self.ids.insert(children, id);
+        id
+    }
+
+    /// The node at `level` with no live cells.
+    fn empty(&mut self, level: u32) -> NodeId {
+        while self.empty.len() <= level as usize {
+            let below = self.empty[self.empty.len() - 1];
+            let id = self.node([below; 4]);
+            self.empty.push(id);
+        }
+        self.empty[level as usize]
+    }
+
+    /// Doubles the width of the root, keeping it centered on the origin.
+    fn expand(&mut self) {
+        let root = self.nodes[self.root];
+        assert!(
+            root.level < MAX_LEVEL,
+            "board too large for HashLife coordinates"
+        );
+        let [nw, ne, sw, se] = root.children;
+        let empty = self.empty(root.level - 1);
+        let children = [
+            self.node([empty, empty, empty, nw]),
+            self.node([empty, empty, ne, empty]),
+            self.node([empty, sw, empty, empty]),
+            self.node([se, empty, empty, empty]),
+        ];
+        self.root = self.node(children);
+    }
+
+    /// The node `id`, at `level`, with the cell at `row`, `col` within it set to `alive`.
+    fn set_in(&mut self, id: NodeId, level: u32, row: u64, col: u64, alive: bool) -> NodeId {
+        if level == 0 {
+            return if alive { ALIVE } else { DEAD };
+        }
+        let half = 1 << (level - 1);
+        let mut children = self.nodes[id].children;
+        let child = &mut children[quadrant(row >= half, col >= half)];
+        *child = self.set_in(*child, level - 1, row % half, col % half, alive);
+        self.node(children)
+    }
+
+    /// Interns the node `id` of `other`, and everything below it, as a node of this board.
+    ///
+    /// `copied` maps the nodes of `other` copied so far to their ids here.
+    fn copy_from(
+        &mut self,
+        other: &HashLife,
+        id: NodeId,
+        copied: &mut HashMap<NodeId, NodeId>,
+    ) -> NodeId {
+        if id == DEAD || id == ALIVE {
+            return id;
+        }
+        if let Some(&copy) = copied.get(&id) {
+            return copy;
+        }
+        let mut children = other.nodes[id].children;
+        for child in &mut children {
+            *child = self.copy_from(other, *child, copied);
+        }
+        let copy = self.node(children);
+        copied.insert(id, copy);
+        copy
+    }
+
+    fn collect_live(&self, id: NodeId, top: i64, left: i64, cells: &mut Vec<(i64, i64)>) {
+        let node = self.nodes[id];
+        if node.population == 0 {
+            return;
+        }
+        if node.level == 0 {
+            cells.push((top, left));
+            return;
+        }
+        let half = 1i64 << (node.level - 1);
+        for (index, &child) in node.children.iter().enumerate() {
+            let (row_offset, col_offset) = (index as i64 / 2 * half, index as i64 % 2 * half);
+            self.collect_live(child, top + row_offset, left + col_offset, cells);
+        }
+    }
+
+    /// The central quadrant-sized square of the node `id`, which must be at level 2 or above.
+    fn center(&mut self, id: NodeId) -> NodeId {
+        let [nw, ne, sw, se] = self.nodes[id].children;
+        self.node([
+            self.nodes[nw].children[3],
+            self.nodes[ne].children[2],
+            self.nodes[sw].children[1],
+            self.nodes[se].children[0],
+        ])
+    }
+
+    /// The 4x4 grid of the quadrants of the quadrants of the node `id`.
+    fn grandchildren(&self, id: NodeId) -> [[NodeId; 4]; 4] {
+        let children = self.nodes[id].children;
+        let mut grid = [[DEAD; 4]; 4];
+        for (row, grid_row) in grid.iter_mut().enumerate() {
+            for (col, cell) in grid_row.iter_mut().enumerate() {
+                let child = children[quadrant(row >= 2, col >= 2)];
+                *cell = self.nodes[child].children[quadrant(row % 2 == 1, col % 2 == 1)];
+            }
+        }
+        grid
+    }
+
+    /// The central half of the node `id` after `2^k` generations, where `k` is at most 2 less
+    /// than the node's level.
+    fn future(&mut self, id: NodeId, k: u32) -> NodeId {
+        let node = self.nodes[id];
+        if node.population == 0 {
+            return self.empty(node.level - 1);
+        }
+        if let Some(&result) = self.results.get(&(id, k)) {
+            return result;
+        }
+        let result = if node.level == 2 {
+            self.step_smallest(id)
+        } else {
+            // nine overlapping squares half the size of this node, which are either advanced
+            // by half the time or just cropped, and then four from those advanced the rest of
+            // the way
+            let grid = self.grandchildren(id);
+            let full = k == node.level - 2;
+            let mut stage = [[DEAD; 3]; 3];
+            for (row, stage_row) in stage.iter_mut().enumerate() {
+                for (col, cell) in stage_row.iter_mut().enumerate() {
+                    let square = self.node([
+                        grid[row][col],
+                        grid[row][col + 1],
+                        grid[row + 1][col],
+                        grid[row + 1][col + 1],
+                    ]);
+                    *cell = if full {
+                        self.future(square, k - 1)
+                    } else {
+                        self.center(square)
+                    };
+                }
+            }
+            let remaining = if full { k - 1 } else { k };
+            let mut quadrants = [DEAD; 4];
+            for (index, quadrant) in quadrants.iter_mut().enumerate() {
+                let (row, col) = (index / 2, index % 2);
+                let square = self.node([
+                    stage[row][col],
+                    stage[row][col + 1],
+                    stage[row + 1][col],
+                    stage[row + 1][col + 1],
+                ]);
+                *quadrant = self.future(square, remaining);
+            }
+            self.node(quadrants)
+        };
+        self.results.insert((id, k), result);
+        result
+    }
+
+    /// The central 2x2 cells of the 4x4 node `id` after one generation.
+    fn step_smallest(&mut self, id: NodeId) -> NodeId {
+        let grid = self.grandchildren(id);
+        let mut next = [DEAD; 4];
+        for (index, cell) in next.iter_mut().enumerate() {
+            let (row, col) = (index / 2 + 1, index % 2 + 1);
+            let living_neighbor_count = (row - 1..=row + 1)
+                .flat_map(|neighbor_row| {
+                    (col - 1..=col + 1).map(move |neighbor_col| (neighbor_row, neighbor_col))
+                })
+                .filter(|&(neighbor_row, neighbor_col)| {
+                    (neighbor_row, neighbor_col) != (row, col)
+                        && grid[neighbor_row][neighbor_col] == ALIVE
+                })
+                .count();
+            if self
+                .rule
+                .is_alive_next(grid[row][col] == ALIVE, living_neighbor_count)
+            {
+                *cell = ALIVE;
+            }
+        }
+        self.node(next)
+    }
+}
+
+/// The index of the child covering the lower half of rows if `south`, and the right half of
+/// columns if `east`.
+fn quadrant(south: bool, east: bool) -> usize {
+    (south as usize) << 1 | east as usize
+}
+
+impl Default for HashLife {
+    fn default() -> HashLife {
+        HashLife::new()
+    }
+}
+
+/// Copies the live cells and rule, keeping their coordinates; the boundary mode and neighborhood
+/// are not carried over.
+///
+/// Fails for the same rules as [`HashLife::with_rule`].
+impl TryFrom<&GameOfLife> for HashLife {
+    type Error = B0RuleError;
+
+    fn try_from(game: &GameOfLife) -> Result<HashLife, B0RuleError> {
+        let mut hashlife = HashLife::new().with_rule(game.rule())?;
+        for (row, col) in game.live_cells() {
+            hashlife.set(row as i64, col as i64, true);
+        }
+        Ok(hashlife)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{patterns, SparseLife};
+
+    fn sorted(mut cells: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+        cells.sort_unstable();
+        cells
+    }
+
+    #[test]
+    fn test_set_and_is_alive() {
+        let mut game = HashLife::new();
+        game.set(-5, 3, true);
+        game.set(1000, -70_000, true);
+        game.set(2, 2, true);
+        game.set(2, 2, false);
+        assert!(game.is_alive(-5, 3));
+        assert!(game.is_alive(1000, -70_000));
+        assert!(!game.is_alive(2, 2));
+        assert!(!game.is_alive(i64::MIN, i64::MAX));
+        assert_eq!(game.population(), 2);
+        assert_eq!(sorted(game.live_cells()), vec![(-5, 3), (1000, -70_000)]);
+    }
+
+    #[test]
+    fn test_matches_sparse_engine() {
+        let gun = patterns::gosper_glider_gun();
+        let mut sparse = SparseLife::try_from(&gun).unwrap();
+        let mut hashlife = HashLife::try_from(&gun).unwrap();
+        for _ in 0..40 {
+            sparse = sparse.next_state();
+        }
+        hashlife.advance(40);
+        assert_eq!(
+            sorted(hashlife.live_cells()),
+            sorted(sparse.live_cells().collect())
+        );
+
+        // larger, uneven jumps
+        for _ in 0..157 {
+            sparse = sparse.next_state();
+        }
+        hashlife.advance(157);
+        assert_eq!(hashlife.generation(), 197);
+        assert_eq!(
+            sorted(hashlife.live_cells()),
+            sorted(sparse.live_cells().collect())
+        );
+    }
+
+    #[test]
+    fn test_glider_after_huge_power_of_two() {
+        let glider = patterns::glider();
+        let mut game = HashLife::try_from(&glider).unwrap();
+        game.advance_pow2(40);
+        assert_eq!(game.generation(), 1 << 40);
+        // a glider moves one cell diagonally every 4 generations
+        let moved = 1i64 << 38;
+        let expected: Vec<_> = glider
+            .live_cells()
+            .into_iter()
+            .map(|(row, col)| (row as i64 + moved, col as i64 + moved))
+            .collect();
+        assert_eq!(sorted(game.live_cells()), sorted(expected));
+    }
+
+    #[test]
+    fn test_to_dense_round_trips() {
+        let blinker = patterns::blinker().padded(1);
+        let mut game = HashLife::try_from(&blinker).unwrap();
+        assert_eq!(game.to_dense((5, 3)), blinker);
+        game.advance(3);
+        assert_eq!(game.to_dense((5, 3)), blinker.next_state());
+
+        let highlife = Rule::parse("B36/S23").unwrap();
+        let game = HashLife::try_from(&blinker.with_rule(highlife)).unwrap();
+        assert_eq!(game.rule(), highlife);
+    }
+
+    #[test]
+    fn test_rejects_b0_rules() {
+        let b0 = Rule::parse("B03/S23").unwrap();
+        let error = B0RuleError { rule: b0 };
+        assert_eq!(HashLife::new().with_rule(b0).unwrap_err(), error);
+        let dense = GameOfLife::dead(3, 3).with_rule(b0);
+        assert_eq!(HashLife::try_from(&dense).unwrap_err(), error);
+    }
+
+    #[test]
+    fn test_compact_keeps_the_board() {
+        let gun = patterns::gosper_glider_gun();
+        let mut game = HashLife::try_from(&gun).unwrap();
+        game.advance(1000);
+        let mut compacted = game.clone();
+        compacted.compact();
+        assert!(compacted.nodes.len() < game.nodes.len());
+        assert!(compacted.results.is_empty());
+        assert_eq!(compacted.generation(), 1000);
+        assert_eq!(sorted(compacted.live_cells()), sorted(game.live_cells()));
+
+        game.advance(1000);
+        compacted.advance(1000);
+        assert_eq!(sorted(compacted.live_cells()), sorted(game.live_cells()));
+    }
+}
@@ -0,0 +1,186 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{GameOfLife, Rule, RuleError, ShapeError};
+
+/// A board of hexagonal cells, each with 6 neighbors, evolving under a life-like [`Rule`].
+///
+/// Cells are stored in offset rows: every odd row is shifted half a cell to the right, so a cell's
+/// neighbors are the two beside it in its row and two each in the rows above and below. Cells
+/// beyond the edges are always dead. The most studied hexagonal rule is `B2/S34`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HexLife {
+    cells: Vec<Vec<bool>>,
+    rule: Rule,
+}
+
+impl HexLife {
+    /// Creates a board from rows of cells, which must all be the same width.
+    pub fn new(rows: Vec<Vec<bool>>, rule: Rule) -> Result<HexLife, ShapeError> {
+        let cells = GameOfLife::new(rows)?.into();
+        Ok(HexLife { cells, rule })
+    }
+
+    /// Parses a hexagonal rulestring such as `B2/S34H`, as [`Rule::parse`] does but allowing the
+    /// trailing `H` that marks rules meant for hexagonal grids.
+    pub fn parse_rule(rulestring: &str) -> Result<Rule, RuleError> {
+        let rulestring = rulestring.trim();
+        Rule::parse(
+            rulestring
+                .strip_suffix(|suffix| suffix == 'H' || suffix == 'h')
+                .unwrap_or(rulestring),
+        )
+    }
+
+    /// The board's cells, indexed as `cells()[row][col]`.
+    pub fn cells(&self) -> &Vec<Vec<bool>> {
+        &self.cells
+    }
+
+    /// The rule this board evolves under.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Computes the following generation, leaving this board unchanged.
+    pub fn next_state(&self) -> HexLife {
+        let cells = self
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(row_num, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(col_num, &alive)| {
+                        self.rule
+                            .is_alive_next(alive, self.count_living_neighbors(row_num, col_num))
+                    })
+                    .collect()
+            })
+            .collect();
+        HexLife {
+            cells,
+            rule: self.rule,
+        }
+    }
+
+    /// The number of living neighbors of the cell at `row_num`, `col_num`.
+    pub fn count_living_neighbors(&self, row_num: usize, col_num: usize) -> usize {
+        // odd rows sit half a cell right of even ones, so their diagonal neighbors do too
+        let shift = (row_num % 2) as isize;
+        let offsets = [
+            (-1, shift - 1),
+            (-1, shift),
+            (0, -1),
+            (0, 1),
+            (1, shift - 1),
+            (1, shift),
+        ];
+        offsets
+            .iter()
+            .filter(|&&(row_offset, col_offset)| {
+                let neighbor_row_num = row_num as isize + row_offset;
+                let neighbor_col_num = col_num as isize + col_offset;
+                neighbor_row_num >= 0
+                    && neighbor_col_num >= 0
+                    && self
+                        .cells
+                        .get(neighbor_row_num as usize)
+                        .and_then(|row| row.get(neighbor_col_num as usize))
+                        .copied()
+                        .unwrap_or(false)
+            })
+            .count()
+    }
+}
+
+/// Renders live cells as `•` and dead cells as `·`, separated by spaces, with odd rows indented by
+/// one character so each cell sits between the two cells it touches in the neighboring rows.
+impl fmt::Display for HexLife {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (row_num, row) in self.cells.iter().enumerate() {
+            if row_num > 0 {
+                f.write_str("\n")?;
+            }
+            if row_num % 2 == 1 {
+                f.write_str(" ")?;
+            }
+            for (col_num, &alive) in row.iter().enumerate() {
+                if col_num > 0 {
+                    f.write_str(" ")?;
+                }
+                f.write_str(if alive { "•" } else { "·" })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(lines: &[&str]) -> HexLife {
+        let cells = GameOfLife::from_lines(lines).into();
+        HexLife::new(cells, Rule::parse("B2/S34").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_count_living_neighbors() {
+        #[rustfmt::skip]
+        let full = hex(&[
+            "•••",
+            "•••",
+            "•••",
+            "•••",
+        ]);
+        assert_eq!(full.count_living_neighbors(1, 1), 6);
+        assert_eq!(full.count_living_neighbors(2, 1), 6);
+        assert_eq!(full.count_living_neighbors(0, 0), 2);
+        assert_eq!(full.count_living_neighbors(1, 2), 3);
+        assert_eq!(full.count_living_neighbors(2, 0), 3);
+    }
+
+    #[test]
+    fn test_pair_oscillates() {
+        #[rustfmt::skip]
+        let pair = hex(&[
+            "    ",
+            "    ",
+            " •• ",
+            "    ",
+            "    ",
+        ]);
+        #[rustfmt::skip]
+        let flipped = hex(&[
+            "    ",
+            " •  ",
+            "    ",
+            " •  ",
+            "    ",
+        ]);
+        assert_eq!(pair.next_state(), flipped);
+        assert_eq!(flipped.next_state(), pair);
+    }
+
+    #[test]
+    fn test_parse_rule() {
+        let rule = Rule::parse("B2/S34").unwrap();
+        assert_eq!(HexLife::parse_rule("B2/S34H"), Ok(rule));
+        assert_eq!(HexLife::parse_rule("b2/s34h"), Ok(rule));
+        assert_eq!(HexLife::parse_rule("B2/S34"), Ok(rule));
+        assert_eq!(HexLife::parse_rule("B2/S34V"), Err(RuleError::Malformed));
+    }
+
+    #[test]
+    fn test_new_rejects_ragged_rows() {
+        let rule = Rule::conway();
+        assert!(HexLife::new(vec![vec![true, false], vec![true]], rule).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let game = hex(&["• ", " •", "••"]);
+        assert_eq!(game.to_string(), "• ·\n · •\n• •");
+    }
+}
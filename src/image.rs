@@ -0,0 +1,562 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::mem;
+use std::path::Path;
+
+use crate::{BufferSizeError, GameOfLife};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// The largest block that an uncompressed deflate block can hold.
+const MAX_STORED_BLOCK: usize = 0xffff;
+
+/// GIF codes for the two palette entries need only 2 bits, the smallest LZW code size allowed.
+const GIF_MIN_CODE_SIZE: u8 = 2;
+const GIF_MAX_CODE_SIZE: u8 = 12;
+
+/// The brightness of live and dead cells in rendered images.
+const ALIVE: u8 = 0x00;
+const DEAD: u8 = 0xff;
+
+impl GameOfLife {
+    /// Creates a board from a row-major buffer of 8 bit grayscale pixels, one per cell, making
+    /// pixels darker than `threshold` alive.
+    ///
+    /// This matches how [`to_png`](GameOfLife::to_png) draws live cells in black, so dark
+    /// strokes drawn on a light background come to life. The buffer must hold exactly
+    /// `rows * cols` pixels.
+    pub fn from_grayscale(
+        rows: usize,
+        cols: usize,
+        pixels: &[u8],
+        threshold: u8,
+    ) -> Result<GameOfLife, BufferSizeError> {
+        GameOfLife::from_flat_with(rows, cols, pixels, |luminance| luminance < threshold)
+    }
+
+    /// Writes this board as a grayscale PNG image, drawing each live cell as a black
+    /// `cell_size`×`cell_size` square on white.
+    ///
+    /// The image is `cols * cell_size` pixels wide and `rows * cell_size` pixels high. PNG images
+    /// cannot be empty, so an empty board or a `cell_size` of 0 is an
+    /// [`io::ErrorKind::InvalidInput`] error.
+    pub fn to_png(&self, cell_size: u32, mut out: impl Write) -> io::Result<()> {
+        let (width, height) = self.image_dimensions(cell_size)?;
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&width.to_be_bytes());
+        header.extend_from_slice(&height.to_be_bytes());
+        // 8 bit grayscale, with the default compression, filtering and no interlacing.
+        header.extend_from_slice(&[8, 0, 0, 0, 0]);
+
+        // Each scanline starts with its filter type, 0 for none.
+        let mut scanlines = Vec::with_capacity((width as usize + 1) * height as usize);
+        for row in self.pixel_rows(cell_size) {
+            scanlines.push(0);
+            scanlines.extend_from_slice(&row);
+        }
+
+        out.write_all(&PNG_SIGNATURE)?;
+        write_chunk(&mut out, b"IHDR", &header)?;
+        write_chunk(&mut out, b"IDAT", &zlib_stored(&scanlines))?;
+        write_chunk(&mut out, b"IEND", &[])
+    }
+
+    /// Writes `generations` successive generations of this board, starting with this one, as the
+    /// frames of a looping GIF animation shown `frame_delay_ms` milliseconds apart.
+    ///
+    /// Each frame is drawn as by [`to_png`](GameOfLife::to_png), and must be between 1 and 65535
+    /// pixels in each direction, or else this is an [`io::ErrorKind::InvalidInput`] error. The
+    /// frames of an [auto-growing](GameOfLife::with_auto_grow) board are all drawn at the size of
+    /// the last, largest one, so its generations are held in memory until that one is known. GIF
+    /// delays are in hundredths of a second, so `frame_delay_ms` is rounded down to a multiple of
+    /// 10.
+    pub fn to_gif(
+        &self,
+        generations: usize,
+        cell_size: u32,
+        frame_delay_ms: u16,
+        mut out: impl Write,
+    ) -> io::Result<()> {
+        // an auto-growing board only gets larger, so its last frame sets the size of them all;
+        // each generation is kept along with how far the board grew on the way to the next
+        let mut grown = Vec::new();
+        if self.auto_grow() && generations > 0 {
+            let mut game = self.clone();
+            for _ in 1..generations {
+                let grown_by = game.growth();
+                let next = game.next_state();
+                grown.push((mem::replace(&mut game, next), grown_by));
+            }
+            grown.push((game, 0));
+        }
+        let canvas = grown.last().map_or(self, |(last, _)| last);
+        let (width, height) = canvas.image_dimensions(cell_size)?;
+        let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+            (Ok(width), Ok(height)) => (width, height),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "GIF images must fit in 65535 pixels each way",
+                ))
+            }
+        };
+
+        out.write_all(b"GIF89a")?;
+        out.write_all(&width.to_le_bytes())?;
+        out.write_all(&height.to_le_bytes())?;
+        // A global color table of 2 entries, with the background being the first.
+        out.write_all(&[0x80, 0, 0])?;
+        out.write_all(&[ALIVE, ALIVE, ALIVE, DEAD, DEAD, DEAD])?;
+        // The NETSCAPE2.0 extension, asking for the animation to loop forever.
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+        let delay = (frame_delay_ms / 10).to_le_bytes();
+        let mut write_frame = |frame: &GameOfLife| -> io::Result<()> {
+            out.write_all(&[0x21, 0xf9, 4, 0, delay[0], delay[1], 0, 0])?;
+            out.write_all(&[0x2c, 0, 0, 0, 0])?;
+            out.write_all(&width.to_le_bytes())?;
+            out.write_all(&height.to_le_bytes())?;
+            out.write_all(&[0, GIF_MIN_CODE_SIZE])?;
+            let indices: Vec<u8> = frame
+                .pixel_rows(cell_size)
+                .flatten()
+                .map(|shade| (shade == DEAD) as u8)
+                .collect();
+            for block in lzw_encode(&indices).chunks(255) {
+                out.write_all(&[block.len() as u8])?;
+                out.write_all(block)?;
+            }
+            out.write_all(&[0])
+        };
+        if grown.is_empty() {
+            let mut game = self.clone();
+            for _ in 0..generations {
+                write_frame(&game)?;
+                game = game.next_state();
+            }
+        } else {
+            // each frame is centered on the last one by all the growth that came after it
+            let mut inset: usize = grown.iter().map(|&(_, grown_by)| grown_by).sum();
+            for (game, grown_by) in &grown {
+                if inset > 0 {
+                    write_frame(&game.padded(inset))?;
+                } else {
+                    write_frame(game)?;
+                }
+                inset -= grown_by;
+            }
+        }
+        out.write_all(&[0x3b])
+    }
+
+    /// Writes this board to the file at `path` as a PNG image, as by
+    /// [`to_png`](GameOfLife::to_png).
+    ///
+    /// An image that can't be drawn is reported before the file is created.
+    pub fn save_png(&self, cell_size: u32, path: impl AsRef<Path>) -> io::Result<()> {
+        self.image_dimensions(cell_size)?;
+        let mut out = BufWriter::new(File::create(path)?);
+        self.to_png(cell_size, &mut out)?;
+        out.flush()
+    }
+
+    /// Writes `generations` generations of this board to the file at `path` as a GIF animation,
+    /// as by [`to_gif`](GameOfLife::to_gif).
+    ///
+    /// An image that can't be drawn is reported before the file is created.
+    pub fn save_gif(
+        &self,
+        generations: usize,
+        cell_size: u32,
+        frame_delay_ms: u16,
+        path: impl AsRef<Path>,
+    ) -> io::Result<()> {
+        self.image_dimensions(cell_size)?;
+        let mut out = BufWriter::new(File::create(path)?);
+        self.to_gif(generations, cell_size, frame_delay_ms, &mut out)?;
+        out.flush()
+    }
+
+    /// The size in pixels of this board rendered with `cell_size` pixel cells, checking that it
+    /// isn't empty.
+    pub(crate) fn image_dimensions(&self, cell_size: u32) -> io::Result<(u32, u32)> {
+        let (rows, cols) = self.dimensions();
+        let scale = |cells: usize| {
+            u32::try_from(cells)
+                .ok()
+                .and_then(|cells| cells.checked_mul(cell_size))
+                .filter(|&pixels| pixels > 0)
+        };
+        match (scale(cols), scale(rows)) {
+            (Some(width), Some(height)) => Ok((width, height)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "image must be non-empty and fit in 2^32 pixels each way",
+            )),
+        }
+    }
+
+    /// This board's rows of pixels, each scaled up `cell_size` times in both directions.
+    pub(crate) fn pixel_rows(&self, cell_size: u32) -> impl Iterator<Item = Vec<u8>> + '_ {
+        let cell_size = cell_size as usize;
+        self.cells().iter().flat_map(move |row| {
+            let pixels: Vec<u8> = row
+                .iter()
+                .flat_map(|&alive| {
+                    let shade = if alive { ALIVE } else { DEAD };
+                    std::iter::repeat_n(shade, cell_size)
+                })
+                .collect();
+            std::iter::repeat_n(pixels, cell_size)
+        })
+    }
+}
+
+/// Writes a PNG chunk: its length, type, data and the CRC of its type and data.
+fn write_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "image is too large"))?;
+    out.write_all(&len.to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    out.write_all(&crc32(kind.iter().chain(data)).to_be_bytes())
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let blocks = data.len().div_ceil(MAX_STORED_BLOCK).max(1);
+    let mut stream = Vec::with_capacity(data.len() + 5 * blocks + 6);
+    stream.extend_from_slice(&[0x78, 0x01]);
+    let mut chunks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if chunks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(chunk) = chunks.next() {
+        let is_final = chunks.peek().is_none();
+        let len = chunk.len() as u16;
+        stream.push(is_final as u8);
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(chunk);
+    }
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32<'a>(bytes: impl Iterator<Item = &'a u8>) -> u32 {
+    !bytes.fold(!0, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| {
+            (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let (a, b) = bytes.iter().fold((1, 0), |(a, b), &byte| {
+        let a = (a + byte as u32) % MOD_ADLER;
+        (a, (b + a) % MOD_ADLER)
+    });
+    b << 16 | a
+}
+
+/// Compresses palette indices with GIF's variable code size LZW.
+fn lzw_encode(indices: &[u8]) -> Vec<u8> {
+    let clear_code = 1 << GIF_MIN_CODE_SIZE;
+    let end_code = clear_code + 1;
+    let mut bits = BitWriter::default();
+    let mut codes = HashMap::new();
+    let mut code_size = GIF_MIN_CODE_SIZE + 1;
+    let mut next_code = end_code + 1;
+
+    bits.write(clear_code, code_size);
+    let mut prefix: Option<u16> = None;
+    for &index in indices {
+        let current = match prefix {
+            None => {
+                prefix = Some(index as u16);
+                continue;
+            }
+            Some(current) => current,
+        };
+        if let Some(&code) = codes.get(&(current, index)) {
+            prefix = Some(code);
+            continue;
+        }
+        bits.write(current, code_size);
+        if next_code < 1 << GIF_MAX_CODE_SIZE {
+            codes.insert((current, index), next_code);
+            next_code += 1;
+            if next_code > 1 << code_size {
+                code_size += 1;
+            }
+        } else {
+            bits.write(clear_code, code_size);
+            codes.clear();
+            code_size = GIF_MIN_CODE_SIZE + 1;
+            next_code = end_code + 1;
+        }
+        prefix = Some(index as u16);
+    }
+    if let Some(current) = prefix {
+        bits.write(current, code_size);
+    }
+    bits.write(end_code, code_size);
+    bits.finish()
+}
+
+/// Packs codes into bytes least significant bit first, as GIF expects.
+#[derive(Debug, Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    pending: u32,
+    pending_bits: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.pending |= (code as u32) << self.pending_bits;
+        self.pending_bits += size;
+        while self.pending_bits >= 8 {
+            self.bytes.push(self.pending as u8);
+            self.pending >>= 8;
+            self.pending_bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.pending_bits > 0 {
+            self.bytes.push(self.pending as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rng, SplitMix64};
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND".iter()), 0xae42_6082);
+        assert_eq!(crc32(b"123456789".iter()), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_pixel_rows() {
+        let game = GameOfLife::from_lines(&["• "]);
+        assert_eq!(
+            game.pixel_rows(2).collect::<Vec<_>>(),
+            vec![
+                vec![ALIVE, ALIVE, DEAD, DEAD],
+                vec![ALIVE, ALIVE, DEAD, DEAD]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_png() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            "•  ",
+            " • ",
+        ]);
+        let mut png = Vec::new();
+        game.to_png(4, &mut png).unwrap();
+        assert_eq!(png[..8], PNG_SIGNATURE);
+        assert_eq!(png[12..16], *b"IHDR");
+        assert_eq!(png[16..24], [0, 0, 0, 12, 0, 0, 0, 8]);
+        assert_eq!(
+            png[png.len() - 8..],
+            [b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]
+        );
+
+        // The stored deflate data is the filtered scanlines themselves.
+        let idat = &png[33 + 8..png.len() - 12 - 4];
+        assert_eq!(idat[..3], [0x78, 0x01, 1]);
+        let scanlines = &idat[7..idat.len() - 4];
+        assert_eq!(scanlines.len(), 8 * 13);
+        assert_eq!(scanlines[..6], [0, ALIVE, ALIVE, ALIVE, ALIVE, DEAD]);
+    }
+
+    #[test]
+    fn test_to_png_rejects_empty_images() {
+        let mut png = Vec::new();
+        let err = GameOfLife::from_lines(&[]).to_png(1, &mut png).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err = GameOfLife::from_lines(&["•"])
+            .to_png(0, &mut png)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(png.is_empty());
+    }
+
+    #[test]
+    fn test_zlib_stored_splits_large_data() {
+        let data = vec![7; MAX_STORED_BLOCK + 1];
+        let stream = zlib_stored(&data);
+        assert_eq!(stream.len(), 2 + 2 * 5 + data.len() + 4);
+        assert_eq!(stream[2..7], [0, 0xff, 0xff, 0, 0]);
+        assert_eq!(
+            stream[7 + MAX_STORED_BLOCK..12 + MAX_STORED_BLOCK],
+            [1, 1, 0, 0xfe, 0xff]
+        );
+    }
+
+    /// A straightforward GIF LZW decoder to check the encoder against.
+    fn lzw_decode(bytes: &[u8]) -> Vec<u8> {
+        let clear_code = 1u16 << GIF_MIN_CODE_SIZE;
+        let end_code = clear_code + 1;
+        let initial_table = || (0..clear_code).map(|index| vec![index as u8]).collect();
+        let mut table: Vec<Vec<u8>> = initial_table();
+        let mut code_size = GIF_MIN_CODE_SIZE + 1;
+        let mut prev: Option<Vec<u8>> = None;
+        let mut output = Vec::new();
+        let mut bit = 0;
+        loop {
+            let code = (0..code_size as usize).fold(0u16, |code, i| {
+                let set = bytes[(bit + i) / 8] >> ((bit + i) % 8) & 1;
+                code | (set as u16) << i
+            });
+            bit += code_size as usize;
+            if code == clear_code {
+                table = initial_table();
+                table.extend([vec![], vec![]]);
+                code_size = GIF_MIN_CODE_SIZE + 1;
+                prev = None;
+                continue;
+            }
+            if code == end_code {
+                return output;
+            }
+            let entry = match (table.get(code as usize), &prev) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(prev)) => [prev.clone(), vec![prev[0]]].concat(),
+                (None, None) => panic!("invalid first code {}", code),
+            };
+            if let Some(prev) = prev {
+                if table.len() < 1 << GIF_MAX_CODE_SIZE {
+                    table.push([prev, vec![entry[0]]].concat());
+                }
+            }
+            if table.len() == 1 << code_size && code_size < GIF_MAX_CODE_SIZE {
+                code_size += 1;
+            }
+            output.extend_from_slice(&entry);
+            prev = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw_round_trips() {
+        assert_eq!(lzw_decode(&lzw_encode(&[])), vec![]);
+        assert_eq!(lzw_decode(&lzw_encode(&[1, 1, 1, 0])), vec![1, 1, 1, 0]);
+
+        // Enough varied input to fill the code table and force a clear code.
+        let mut rng = SplitMix64::new(1);
+        let indices: Vec<u8> = (0..50_000).map(|_| (rng.next_f64() < 0.3) as u8).collect();
+        assert_eq!(lzw_decode(&lzw_encode(&indices)), indices);
+    }
+
+    #[test]
+    fn test_to_gif() {
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "   ",
+            "•••",
+            "   ",
+        ]);
+        let mut gif = Vec::new();
+        blinker.to_gif(2, 2, 250, &mut gif).unwrap();
+        assert_eq!(gif[..13], *b"GIF89a\x06\x00\x06\x00\x80\x00\x00");
+        assert_eq!(gif[gif.len() - 1], 0x3b);
+
+        // Each frame starts with a graphic control extension holding its delay.
+        let frame_start = 13 + 6 + 19;
+        assert_eq!(
+            gif[frame_start..frame_start + 8],
+            [0x21, 0xf9, 4, 0, 25, 0, 0, 0]
+        );
+        let data_start = frame_start + 8 + 10;
+        assert_eq!(gif[data_start], GIF_MIN_CODE_SIZE);
+        let block_len = gif[data_start + 1] as usize;
+        let pixels = lzw_decode(&gif[data_start + 2..data_start + 2 + block_len]);
+        assert_eq!(pixels[..12], [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]);
+        assert_eq!(pixels[12..18], [0, 0, 0, 0, 0, 0]);
+        assert_eq!(gif[data_start + 2 + block_len], 0);
+
+        let second_frame = data_start + 3 + block_len;
+        assert_eq!(gif[second_frame..second_frame + 2], [0x21, 0xf9]);
+    }
+
+    #[test]
+    fn test_save_images() {
+        let glider = crate::patterns::glider();
+        let path = std::env::temp_dir().join(format!("game_of_life_{}_glider", std::process::id()));
+        let (png, gif) = (path.with_extension("png"), path.with_extension("gif"));
+
+        glider.save_png(3, &png).unwrap();
+        let mut expected = Vec::new();
+        glider.to_png(3, &mut expected).unwrap();
+        assert_eq!(std::fs::read(&png).unwrap(), expected);
+
+        glider.save_gif(4, 3, 100, &gif).unwrap();
+        expected.clear();
+        glider.to_gif(4, 3, 100, &mut expected).unwrap();
+        assert_eq!(std::fs::read(&gif).unwrap(), expected);
+
+        std::fs::remove_file(&png).unwrap();
+        std::fs::remove_file(&gif).unwrap();
+        assert!(glider.save_png(0, &png).is_err());
+        assert!(!png.exists());
+    }
+
+    #[test]
+    fn test_to_gif_sizes_growing_boards_by_last_frame() {
+        let growing = crate::patterns::glider().with_auto_grow(true);
+        let last = growing.nth_generation(5);
+        assert!(last.dimensions() > growing.dimensions());
+        let roomy = crate::patterns::glider().padded((last.dimensions().0 - 3) / 2);
+        assert_eq!(roomy.dimensions(), last.dimensions());
+
+        let (mut gif, mut expected) = (Vec::new(), Vec::new());
+        growing.to_gif(6, 2, 100, &mut gif).unwrap();
+        roomy.to_gif(6, 2, 100, &mut expected).unwrap();
+        assert_eq!(gif, expected);
+    }
+
+    #[test]
+    fn test_from_grayscale() {
+        let pixels = [0, 200, 255, 127, 128, 10];
+        assert_eq!(
+            GameOfLife::from_grayscale(2, 3, &pixels, 128),
+            Ok(GameOfLife::from_lines(&["•  ", "• •"]))
+        );
+        assert_eq!(
+            GameOfLife::from_grayscale(3, 2, &pixels, 0),
+            Ok(GameOfLife::from_lines(&["  ", "  ", "  "]))
+        );
+        assert_eq!(
+            GameOfLife::from_grayscale(2, 2, &pixels, 128),
+            Err(BufferSizeError {
+                len: 6,
+                expected: 4,
+            })
+        );
+        assert_eq!(
+            GameOfLife::from_grayscale(0, 0, &[], 128),
+            Ok(GameOfLife::from_lines(&[]))
+        );
+    }
+
+    #[test]
+    fn test_grayscale_round_trips_through_pixel_rows() {
+        let game = GameOfLife::from_lines(&["• •", " • "]);
+        let pixels: Vec<u8> = game.pixel_rows(1).flatten().collect();
+        assert_eq!(GameOfLife::from_grayscale(2, 3, &pixels, 128), Ok(game));
+    }
+}
@@ -0,0 +1,233 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{GameOfLife, Neighborhood, RuleError};
+
+/// A rule from the Larger than Life family, such as Bosco's Rule,
+/// `R5,C0,M1,S34..58,B34..45,NM`.
+///
+/// Neighbors are counted out to a radius, in the Moore or von Neumann neighborhood, and a cell is
+/// born or survives when its count falls within a range rather than matching one of a set of
+/// counts. The rule may also count the cell itself as one of its neighbors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LargerThanLifeRule {
+    radius: usize,
+    neighborhood: Neighborhood,
+    count_middle: bool,
+    birth: (usize, usize),
+    survival: (usize, usize),
+}
+
+impl LargerThanLifeRule {
+    /// Bosco's Rule, `R5,C0,M1,S34..58,B34..45,NM`.
+    pub fn bosco() -> LargerThanLifeRule {
+        LargerThanLifeRule {
+            radius: 5,
+            neighborhood: Neighborhood::Moore,
+            count_middle: true,
+            birth: (34, 45),
+            survival: (34, 58),
+        }
+    }
+
+    /// Parses a rulestring in Golly's notation, such as `R5,C0,M1,S34..58,B34..45,NM`.
+    ///
+    /// `R` gives the radius, `S` and `B` the inclusive survival and birth ranges, `M1` counts
+    /// the cell itself among its neighbors, and `NM` or `NN` picks the Moore or von Neumann
+    /// neighborhood. `C` is the number of states, where only 0 and 2 both mean a two-state rule.
+    /// `R`, `S` and `B` are required, and the rest default to `C0`, `M0` and `NM`.
+    pub fn parse(rulestring: &str) -> Result<LargerThanLifeRule, RuleError> {
+        let (mut radius, mut birth, mut survival) = (None, None, None);
+        let mut rule = LargerThanLifeRule {
+            neighborhood: Neighborhood::Moore,
+            count_middle: false,
+            ..LargerThanLifeRule::bosco()
+        };
+        for part in rulestring.trim().split(',') {
+            let part = part.trim();
+            let mut chars = part.chars();
+            let key = chars.next().ok_or(RuleError::Malformed)?;
+            let value = chars.as_str();
+            match key.to_ascii_uppercase() {
+                'R' => radius = Some(parse_number(value)?),
+                'C' => match parse_number(value)? {
+                    0 | 2 => {}
+                    states => return Err(RuleError::StatesOutOfRange(states as u32)),
+                },
+                'M' => {
+                    rule.count_middle = match value {
+                        "0" => false,
+                        "1" => true,
+                        _ => return Err(RuleError::Malformed),
+                    }
+                }
+                'S' => survival = Some(parse_range(value)?),
+                'B' => birth = Some(parse_range(value)?),
+                'N' => {
+                    rule.neighborhood = match value {
+                        "M" | "m" => Neighborhood::Moore,
+                        "N" | "n" => Neighborhood::VonNeumann,
+                        _ => return Err(RuleError::Malformed),
+                    }
+                }
+                _ => return Err(RuleError::Malformed),
+            }
+        }
+        match (radius, birth, survival) {
+            (Some(radius), Some(birth), Some(survival)) if radius > 0 => Ok(LargerThanLifeRule {
+                radius,
+                birth,
+                survival,
+                ..rule
+            }),
+            _ => Err(RuleError::Malformed),
+        }
+    }
+
+    /// How far away neighbors are counted.
+    pub fn radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Which surrounding cells count as neighbors.
+    pub fn neighborhood(&self) -> Neighborhood {
+        self.neighborhood
+    }
+
+    /// Decides whether a cell is alive in the next generation, given the number of living cells
+    /// around it, not counting itself.
+    pub fn is_alive_next(&self, currently_alive: bool, living_neighbor_count: usize) -> bool {
+        let count = living_neighbor_count + (self.count_middle && currently_alive) as usize;
+        let (low, high) = if currently_alive {
+            self.survival
+        } else {
+            self.birth
+        };
+        (low..=high).contains(&count)
+    }
+}
+
+fn parse_number(value: &str) -> Result<usize, RuleError> {
+    value.parse().map_err(|_| RuleError::Malformed)
+}
+
+/// Parses an inclusive range such as `34..58`, or a single count such as `3`.
+fn parse_range(value: &str) -> Result<(usize, usize), RuleError> {
+    let (low, high) = match value.split_once("..") {
+        Some((low, high)) => (parse_number(low)?, parse_number(high)?),
+        None => (parse_number(value)?, parse_number(value)?),
+    };
+    if low <= high {
+        Ok((low, high))
+    } else {
+        Err(RuleError::Malformed)
+    }
+}
+
+/// Parses a rulestring as [`LargerThanLifeRule::parse`] does.
+impl FromStr for LargerThanLifeRule {
+    type Err = RuleError;
+
+    fn from_str(rulestring: &str) -> Result<LargerThanLifeRule, RuleError> {
+        LargerThanLifeRule::parse(rulestring)
+    }
+}
+
+/// Renders the rule in Golly's `R<radius>,C0,M<0|1>,S<low>..<high>,B<low>..<high>,N<M|N>`
+/// notation.
+impl fmt::Display for LargerThanLifeRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "R{},C0,M{},S{}..{},B{}..{},N{}",
+            self.radius,
+            self.count_middle as u8,
+            self.survival.0,
+            self.survival.1,
+            self.birth.0,
+            self.birth.1,
+            match self.neighborhood {
+                Neighborhood::Moore => 'M',
+                Neighborhood::VonNeumann => 'N',
+            }
+        )
+    }
+}
+
+impl GameOfLife {
+    /// Returns the next generation of this board under a Larger than Life rule.
+    ///
+    /// The returned board counts neighbors with the rule's [`radius`](LargerThanLifeRule::radius)
+    /// and [`neighborhood`](LargerThanLifeRule::neighborhood), whatever this board's settings,
+    /// so it can be stepped again with the same rule.
+    pub fn next_state_ltl(&self, rule: &LargerThanLifeRule) -> GameOfLife {
+        self.clone()
+            .with_radius(rule.radius)
+            .with_neighborhood(rule.neighborhood)
+            .next_state_with(|alive, count| rule.is_alive_next(alive, count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BoundaryMode, SplitMix64};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "R5,C0,M1,S34..58,B34..45,NM".parse(),
+            Ok(LargerThanLifeRule::bosco())
+        );
+        assert_eq!(
+            LargerThanLifeRule::bosco().to_string(),
+            "R5,C0,M1,S34..58,B34..45,NM"
+        );
+        let rule = LargerThanLifeRule::parse("r2, s3, b1..4, nn").unwrap();
+        assert_eq!(rule.radius(), 2);
+        assert_eq!(rule.neighborhood(), Neighborhood::VonNeumann);
+        assert_eq!(rule.to_string(), "R2,C0,M0,S3..3,B1..4,NN");
+
+        assert_eq!(
+            LargerThanLifeRule::parse("R5,C3,S34..58,B34..45"),
+            Err(RuleError::StatesOutOfRange(3))
+        );
+        for invalid in &["", "R5,S34..58", "R0,S1,B1", "R1,S3..2,B3", "R1,S2,B3,X1"] {
+            assert_eq!(
+                LargerThanLifeRule::parse(invalid),
+                Err(RuleError::Malformed),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_radius_1_ranges_match_conway() {
+        let soup = GameOfLife::random(16, 16, 0.4, &mut SplitMix64::new(275))
+            .with_boundary(BoundaryMode::Toroidal);
+        // counting the cell itself shifts survival from 2..3 up to 3..4
+        for rulestring in &["R1,C0,M0,S2..3,B3..3,NM", "R1,C0,M1,S3..4,B3..3,NM"] {
+            let rule = LargerThanLifeRule::parse(rulestring).unwrap();
+            let mut game = soup.clone();
+            let mut ltl = soup.clone();
+            for _ in 0..10 {
+                game = game.next_state();
+                ltl = ltl.next_state_ltl(&rule);
+                assert_eq!(ltl.cells(), game.cells(), "{}", rulestring);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bosco_counts_within_radius_5() {
+        // a lone 11x11 square has 121 live cells in the middle cell's neighborhood, too many to
+        // survive, while its corners see only the 36 of a 6x6 quarter
+        let square = GameOfLife::from_lines(&["•••••••••••"; 11]).padded(5);
+        let next = square.next_state_ltl(&LargerThanLifeRule::bosco());
+        assert_eq!(next.radius(), 5);
+        assert!(!next.cells()[10][10]);
+        assert!(next.cells()[5][5]);
+        assert!(next.cells()[15][15]);
+    }
+}
@@ -1,63 +1,907 @@
-use std::cmp::min;
+#![cfg_attr(not(feature = "std"), no_std)]
 
-#[derive(Debug, PartialEq)]
-struct GameOfLife(Vec<Vec<bool>>);
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::cmp::{max, min};
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::mem;
+use core::ops::Index;
+use core::str::FromStr;
+
+mod analysis;
+#[cfg(feature = "std")]
+mod animate;
+mod arena;
+mod automaton;
+mod bitboard;
+mod boundary;
+mod builder;
+mod census;
+mod edit;
+#[cfg(feature = "std")]
+pub mod export;
+mod fixed;
+mod formats;
+mod generations;
+#[cfg(feature = "std")]
+mod hashlife;
+mod hex;
+#[cfg(feature = "std")]
+mod image;
+mod larger_than_life;
+mod multi_state;
+mod neighborhood;
+mod packed;
+#[cfg(feature = "std")]
+mod parallel;
+pub mod patterns;
+mod random;
+mod render;
+mod rule;
+mod simulation;
+#[cfg(feature = "std")]
+mod sparse;
+mod summed_area;
+mod symmetry;
+mod transform;
+
+pub use analysis::{BoardStats, Outcome, StabilityReport, StepStats};
+pub use arena::{Arena, Collision};
+pub use automaton::{Automaton, Evolution};
+pub use bitboard::BitBoard8;
+pub use boundary::BoundaryMode;
+pub use builder::GameOfLifeBuilder;
+pub use census::Census;
+pub use fixed::FixedLife;
+pub use formats::{
+    CheckpointError, JsonError, Life106Error, MacrocellError, RleError, MAX_BOARD_BYTES,
+};
+pub use generations::Generations;
+#[cfg(feature = "std")]
+pub use hashlife::HashLife;
+pub use hex::HexLife;
+pub use larger_than_life::LargerThanLifeRule;
+pub use multi_state::{GenerationsRule, MultiStateLife};
+pub use neighborhood::Neighborhood;
+pub use packed::PackedLife;
+#[cfg(feature = "std")]
+pub use parallel::evolve_many;
+pub use random::{Rng, SplitMix64};
+pub use render::{RenderOptions, SvgOptions};
+pub use rule::{Rule, RuleError, RuleTable};
+pub use simulation::Simulation;
+#[cfg(feature = "std")]
+pub use sparse::SparseLife;
+pub use symmetry::Symmetry;
+
+use summed_area::SummedArea;
+
+/// A finite Game of Life board, stored as rows of cells where `true` is alive.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameOfLife {
+    cells: Vec<Vec<bool>>,
+    boundary: BoundaryMode,
+    neighborhood: Neighborhood,
+    radius: usize,
+    rule: Rule,
+    auto_grow: bool,
+    scratch: Scratch,
+}
+
+/// Spare storage that [`GameOfLife::step_mut`] computes the next generation into.
+///
+/// It holds no part of the board's state, so it never affects equality or hashing, and clones
+/// start empty.
+#[derive(Default)]
+struct Scratch {
+    cells: Vec<Vec<bool>>,
+    sums: SummedArea,
+}
+
+impl Clone for Scratch {
+    fn clone(&self) -> Scratch {
+        Scratch::default()
+    }
+}
+
+impl PartialEq for Scratch {
+    fn eq(&self, _other: &Scratch) -> bool {
+        true
+    }
+}
+
+impl Eq for Scratch {}
+
+impl Hash for Scratch {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl fmt::Debug for Scratch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Scratch").finish_non_exhaustive()
+    }
+}
 
 impl GameOfLife {
-    fn current_state(&self) -> &Vec<Vec<bool>> {
-        let GameOfLife(state) = self;
-        state
+    /// Creates a board from rows of cells, which must all be the same width.
+    ///
+    /// An empty `Vec` is a valid 0x0 board.
+    pub fn new(rows: Vec<Vec<bool>>) -> Result<GameOfLife, ShapeError> {
+        if let Some(first_row) = rows.first() {
+            let width = first_row.len();
+            if let Some((row, bad_row)) =
+                rows.iter().enumerate().find(|(_, row)| row.len() != width)
+            {
+                return Err(ShapeError {
+                    row,
+                    len: bad_row.len(),
+                    expected: width,
+                });
+            }
+        }
+        Ok(GameOfLife::from_cells(rows))
+    }
+
+    /// Creates a board from rows of cells, padding rows shorter than the widest with dead cells
+    /// on the right.
+    ///
+    /// This is the lenient counterpart to [`GameOfLife::new`], as
+    /// [`GameOfLife::from_lines`] is to [`GameOfLife::parse_strict`].
+    pub fn new_padded(mut rows: Vec<Vec<bool>>) -> GameOfLife {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, false);
+        }
+        GameOfLife::from_cells(rows)
+    }
+
+    /// Creates a `rows` by `cols` board where every cell is dead.
+    pub fn dead(rows: usize, cols: usize) -> GameOfLife {
+        GameOfLife::from_cells(vec![vec![false; cols]; rows])
+    }
+
+    /// Wraps already-rectangular cells in a board with the default settings.
+    fn from_cells(cells: Vec<Vec<bool>>) -> GameOfLife {
+        GameOfLife {
+            cells,
+            boundary: BoundaryMode::default(),
+            neighborhood: Neighborhood::default(),
+            radius: 1,
+            rule: Rule::default(),
+            auto_grow: false,
+            scratch: Scratch::default(),
+        }
+    }
+
+    /// Wraps already-rectangular cells in a board with the same settings as this one.
+    fn with_cells(&self, cells: Vec<Vec<bool>>) -> GameOfLife {
+        GameOfLife {
+            cells,
+            boundary: self.boundary,
+            neighborhood: self.neighborhood,
+            radius: self.radius,
+            rule: self.rule,
+            auto_grow: self.auto_grow,
+            scratch: Scratch::default(),
+        }
+    }
+
+    /// Returns this board with its edges treated according to `boundary`.
+    pub fn with_boundary(self, boundary: BoundaryMode) -> GameOfLife {
+        GameOfLife { boundary, ..self }
+    }
+
+    /// How neighbor counting treats the edges of this board.
+    pub fn boundary(&self) -> BoundaryMode {
+        self.boundary
+    }
+
+    /// Returns this board counting the neighbors in `neighborhood`.
+    pub fn with_neighborhood(self, neighborhood: Neighborhood) -> GameOfLife {
+        GameOfLife {
+            neighborhood,
+            ..self
+        }
+    }
+
+    /// Which surrounding cells count as neighbors, which is [`Neighborhood::Moore`] unless changed.
+    pub fn neighborhood(&self) -> Neighborhood {
+        self.neighborhood
+    }
+
+    /// Returns this board counting neighbors up to `radius` cells away, rather than only the
+    /// adjacent cells, as in the Larger than Life family of rules.
+    ///
+    /// With the Moore neighborhood, a radius of 2 counts the 24 other cells of the 5x5 square
+    /// centered on each cell. Neighbor counts above 8 never satisfy a [`Rule`], so larger
+    /// radiuses are mostly useful with [`next_state_with`](GameOfLife::next_state_with) or a
+    /// [`LargerThanLifeRule`].
+    pub fn with_radius(self, radius: usize) -> GameOfLife {
+        GameOfLife { radius, ..self }
+    }
+
+    /// How far away neighbors are counted, which is 1 unless changed.
+    pub fn radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Returns this board growing, or not, to make room for its pattern.
+    ///
+    /// A growing board adds a ring of dead cells, as wide as the
+    /// [`radius`](GameOfLife::radius), around itself before any step in which a live cell is
+    /// that close to an edge, so a pattern is never clipped however far it spreads. Growing
+    /// only makes sense with [`BoundaryMode::Dead`], since the added cells push the edges
+    /// apart; [`trimmed`](GameOfLife::trimmed) shrinks the board back to its live cells.
+    pub fn with_auto_grow(self, auto_grow: bool) -> GameOfLife {
+        GameOfLife { auto_grow, ..self }
+    }
+
+    /// Whether this board grows to make room for its pattern, which is `false` unless changed.
+    pub fn auto_grow(&self) -> bool {
+        self.auto_grow
+    }
+
+    /// How many rows and columns of dead cells this board adds on every side before its next
+    /// step: the radius if it auto-grows and has a live cell within the radius of an edge, and
+    /// otherwise 0.
+    ///
+    /// A cell at `(row, col)` before the step is at `(row + growth, col + growth)` after it.
+    pub(crate) fn growth(&self) -> usize {
+        let (rows, cols) = self.dimensions();
+        let radius = self.radius;
+        if !self.auto_grow || radius == 0 {
+            return 0;
+        }
+        let near_edge = |index: usize, len: usize| index < radius || index + radius >= len;
+        let grows = self.cells.iter().enumerate().any(|(row_num, row)| {
+            if near_edge(row_num, rows) {
+                row.contains(&true)
+            } else {
+                let edge = radius.min(cols);
+                row[..edge].contains(&true) || row[cols - edge..].contains(&true)
+            }
+        });
+        if grows {
+            radius
+        } else {
+            0
+        }
     }
 
-    fn next_state(&self) -> GameOfLife {
-        fn is_alive_next(currently_alive: bool, living_neighbor_count: usize) -> bool {
-            /*
-             * https://en.wikipedia.org/wiki/Conway%27s_Game_of_Life#Rules
-             * Any live cell with two or three live neighbors survives.
-             * Any dead cell with three live neighbors becomes a live cell.
-             * All other live cells die in the next generation. Similarly, all other dead cells stay dead.
-             */
-            currently_alive && living_neighbor_count == 2 || living_neighbor_count == 3
+    /// Adds the ring of dead cells that this board needs before its next step, returning its
+    /// [`growth`](GameOfLife::growth).
+    pub(crate) fn grow(&mut self) -> usize {
+        let growth = self.growth();
+        if growth > 0 {
+            *self = self.padded(growth);
         }
+        growth
+    }
 
-        GameOfLife(
-            self.current_state()
+    /// Returns this board evolving under `rule` instead of its current rule.
+    pub fn with_rule(self, rule: Rule) -> GameOfLife {
+        GameOfLife { rule, ..self }
+    }
+
+    /// The rule this board evolves under, which is Conway's `B3/S23` unless changed.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Parses a board from lines of text, treating any character other than a space as alive.
+    ///
+    /// Lines shorter than the widest line are padded with dead cells.
+    pub fn from_lines(lines: &[&str]) -> GameOfLife {
+        GameOfLife::parse_lines(lines, |char| char != ' ')
+    }
+
+    /// Parses a board from lines of text, treating only `alive` as a live cell.
+    ///
+    /// Lines shorter than the widest line are padded with dead cells.
+    pub fn from_lines_with(lines: &[&str], alive: char) -> GameOfLife {
+        GameOfLife::parse_lines(lines, |char| char == alive)
+    }
+
+    /// Parses a board from text, one line per row, accepting only `alive` and `dead` characters
+    /// and requiring every line to be the same width.
+    ///
+    /// This is the strict counterpart to [`GameOfLife::from_lines_with`]. Empty text is the empty
+    /// board.
+    pub fn parse_strict(text: &str, alive: char, dead: char) -> Result<GameOfLife, ParseError> {
+        let lines: Vec<&str> = text.lines().collect();
+        check_chars(&lines, |ch| ch == alive || ch == dead)?;
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        if let Some(index) = lines.iter().position(|line| line.chars().count() != width) {
+            return Err(ParseError::JaggedRows { line: index + 1 });
+        }
+        Ok(GameOfLife::from_lines_with(&lines, alive))
+    }
+
+    /// Renders this board with `alive` for live cells and `dead` for dead cells, one line per
+    /// row, like [`Display`](fmt::Display) but with the given characters; see
+    /// [`render`](GameOfLife::render) for other options.
+    ///
+    /// As long as the two characters differ and aren't newlines, the output parses back to an
+    /// equal board with [`GameOfLife::parse_strict`] or [`GameOfLife::from_lines_with`].
+    pub fn render_with(&self, alive: char, dead: char) -> String {
+        self.render(&RenderOptions {
+            alive,
+            dead,
+            border: false,
+        })
+    }
+
+    fn parse_lines(lines: &[&str], is_alive: impl Fn(char) -> bool) -> GameOfLife {
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        GameOfLife::from_cells(
+            lines
                 .iter()
-                .enumerate()
-                .map(|(row_num, row)| {
-                    row.iter()
-                        .enumerate()
-                        .map(|(col_num, currently_alive)| {
-                            let living_neighbor_count =
-                                self.count_living_neighbors(row_num, col_num);
-                            is_alive_next(*currently_alive, living_neighbor_count)
-                        })
-                        .collect()
+                .map(|line| {
+                    let mut row: Vec<bool> = line.chars().map(&is_alive).collect();
+                    row.resize(width, false);
+                    row
                 })
                 .collect(),
         )
     }
 
-    fn count_living_neighbors(&self, row_num: usize, col_num: usize) -> usize {
-        // saturating_sub will quietly avoid going lower than 0
-        let min_row = row_num.saturating_sub(1);
-        let min_col = col_num.saturating_sub(1);
-        let max_row = min(self.current_state().len() - 1, row_num + 1);
-        (min_row..=max_row)
-            .flat_map(|neighbor_row_num| {
-                let neighbor_row = &self.current_state()[neighbor_row_num];
-                let max_col = min(neighbor_row.len() - 1, col_num + 1);
-                (min_col..=max_col).filter(move |&neighbor_col_num| {
-                    let current_cell = neighbor_row_num == row_num && neighbor_col_num == col_num;
-                    !current_cell && neighbor_row[neighbor_col_num]
+    /// The board's cells, indexed as `cells()[row][col]`.
+    pub fn cells(&self) -> &Vec<Vec<bool>> {
+        &self.cells
+    }
+
+    /// The board's size as `(rows, cols)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        let cols = self.cells.first().map_or(0, Vec::len);
+        (self.cells.len(), cols)
+    }
+
+    /// The number of living cells.
+    pub fn population(&self) -> usize {
+        self.cells.iter().flatten().filter(|&&alive| alive).count()
+    }
+
+    /// Whether no cell is alive, as on a 0x0 board.
+    pub fn is_extinct(&self) -> bool {
+        !self.cells.iter().flatten().any(|&alive| alive)
+    }
+
+    /// The number of living cells in the rectangle from `top`, `left` to `bottom`, `right`
+    /// inclusive.
+    ///
+    /// The rectangle is clipped to the board, and is empty if `bottom < top` or `right < left`.
+    pub fn population_in(&self, top: usize, left: usize, bottom: usize, right: usize) -> usize {
+        self.cells
+            .iter()
+            .take(bottom.saturating_add(1))
+            .skip(top)
+            .flat_map(|row| row.iter().take(right.saturating_add(1)).skip(left))
+            .filter(|&&alive| alive)
+            .count()
+    }
+
+    /// Creates a `rows` by `cols` board where only the cells at `coords`, given as `(row, col)`,
+    /// are alive.
+    ///
+    /// A coordinate outside the board is an error rather than being clipped.
+    pub fn from_coords(
+        rows: usize,
+        cols: usize,
+        coords: &[(usize, usize)],
+    ) -> Result<GameOfLife, OutOfBounds> {
+        let mut game = GameOfLife::dead(rows, cols);
+        for &(row, col) in coords {
+            game.set(row, col, true)?;
+        }
+        Ok(game)
+    }
+
+    /// Creates a `rows` by `cols` board from a row-major buffer with one byte per cell, where 0
+    /// is dead and anything else, typically 1, is alive.
+    ///
+    /// The buffer must hold exactly `rows * cols` bytes.
+    pub fn from_flat(
+        rows: usize,
+        cols: usize,
+        cells: &[u8],
+    ) -> Result<GameOfLife, BufferSizeError> {
+        GameOfLife::from_flat_with(rows, cols, cells, |cell| cell != 0)
+    }
+
+    pub(crate) fn from_flat_with(
+        rows: usize,
+        cols: usize,
+        cells: &[u8],
+        is_alive: impl Fn(u8) -> bool,
+    ) -> Result<GameOfLife, BufferSizeError> {
+        BufferSizeError::check(rows, cols, cells.len())?;
+        Ok(GameOfLife::from_cells(
+            (0..rows)
+                .map(|row| {
+                    let row = &cells[row * cols..(row + 1) * cols];
+                    row.iter().map(|&cell| is_alive(cell)).collect()
                 })
+                .collect(),
+        ))
+    }
+
+    /// This board as a row-major buffer with one byte per cell, 1 for alive and 0 for dead.
+    pub fn to_flat(&self) -> Vec<u8> {
+        self.cells
+            .iter()
+            .flatten()
+            .map(|&alive| alive as u8)
+            .collect()
+    }
+
+    /// The `(row, col)` coordinates of every live cell, in row-major order.
+    pub fn live_cells(&self) -> Vec<(usize, usize)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .flat_map(|(row_num, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, &alive)| alive)
+                    .map(move |(col_num, _)| (row_num, col_num))
+            })
+            .collect()
+    }
+
+    /// The smallest rectangle containing every live cell, as inclusive
+    /// `(min_row, min_col, max_row, max_col)`, or `None` if no cell is alive.
+    pub fn bounding_box(&self) -> Option<(usize, usize, usize, usize)> {
+        let mut bounds = None;
+        for (row_num, row) in self.cells.iter().enumerate() {
+            for (col_num, _) in row.iter().enumerate().filter(|(_, &alive)| alive) {
+                let (min_row, min_col, _, max_col) =
+                    bounds.unwrap_or((row_num, col_num, row_num, col_num));
+                bounds = Some((
+                    min_row,
+                    min(min_col, col_num),
+                    row_num,
+                    max(max_col, col_num),
+                ));
+            }
+        }
+        bounds
+    }
+
+    /// Whether the cell at `row`, `col` is alive, or `None` if it is outside the board.
+    pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+        self.cells.get(row)?.get(col).copied()
+    }
+
+    /// Sets whether the cell at `row`, `col` is alive.
+    pub fn set(&mut self, row: usize, col: usize, alive: bool) -> Result<(), OutOfBounds> {
+        *self.cell_mut(row, col)? = alive;
+        Ok(())
+    }
+
+    /// Flips the cell at `row`, `col` between alive and dead.
+    pub fn toggle(&mut self, row: usize, col: usize) -> Result<(), OutOfBounds> {
+        let cell = self.cell_mut(row, col)?;
+        *cell = !*cell;
+        Ok(())
+    }
+
+    fn cell_mut(&mut self, row: usize, col: usize) -> Result<&mut bool, OutOfBounds> {
+        self.cells
+            .get_mut(row)
+            .and_then(|cells| cells.get_mut(col))
+            .ok_or(OutOfBounds { row, col })
+    }
+
+    /// Computes the following generation, leaving this board unchanged.
+    pub fn next_state(&self) -> GameOfLife {
+        self.next_state_with(|currently_alive, living_neighbor_count| {
+            self.rule
+                .is_alive_next(currently_alive, living_neighbor_count)
+        })
+    }
+
+    /// Computes the following generation using `rule` in place of the board's own rule.
+    ///
+    /// `rule` is given whether a cell is currently alive and how many living neighbors it has,
+    /// and returns whether the cell is alive in the next generation. Unless `rule` brings dead
+    /// cells with no living neighbors to life, it isn't called for rows where no cell is alive
+    /// in or beside the row, as every cell in them stays dead.
+    pub fn next_state_with(&self, rule: impl Fn(bool, usize) -> bool) -> GameOfLife {
+        let growth = self.growth();
+        if growth > 0 {
+            return self.padded(growth).next_state_with(rule);
+        }
+        let mut cells = Vec::new();
+        self.write_next_cells(&mut SummedArea::default(), &mut cells, &rule);
+        self.with_cells(cells)
+    }
+
+    /// Advances this board to the following generation in place.
+    ///
+    /// The next generation is computed into a buffer that is kept and swapped with the current
+    /// cells, so repeated steps only allocate on the first call.
+    pub fn step_mut(&mut self) {
+        let rule = self.rule;
+        self.step_mut_with(&|alive, count| rule.is_alive_next(alive, count));
+    }
+
+    /// Advances this board to the following generation in place, as
+    /// [`step_mut`](GameOfLife::step_mut) does, and returns whether any cell changed.
+    ///
+    /// Changes are noted as each cell is computed, so `false`, meaning the board is a still
+    /// life, costs no comparison of the old and new boards.
+    pub fn step_mut_changed(&mut self) -> bool {
+        let rule = self.rule;
+        let changed = Cell::new(false);
+        self.step_mut_with(&|alive, count| {
+            let alive_next = rule.is_alive_next(alive, count);
+            if alive_next != alive {
+                changed.set(true);
+            }
+            alive_next
+        });
+        changed.get()
+    }
+
+    /// Advances this board by `generations` generations in place, stepping as
+    /// [`step_mut`](GameOfLife::step_mut) does, so that only the first step allocates.
+    pub fn advance(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step_mut();
+        }
+    }
+
+    fn step_mut_with(&mut self, rule: &impl Fn(bool, usize) -> bool) {
+        self.grow();
+        let mut scratch = mem::take(&mut self.scratch);
+        self.write_next_cells(&mut scratch.sums, &mut scratch.cells, rule);
+        mem::swap(&mut self.cells, &mut scratch.cells);
+        self.scratch = scratch;
+    }
+
+    /// Overwrites `next` with the following generation under `rule`, using `sums` to count
+    /// neighbors in constant time per cell.
+    fn write_next_cells(
+        &self,
+        sums: &mut SummedArea,
+        next: &mut Vec<Vec<bool>>,
+        rule: &impl Fn(bool, usize) -> bool,
+    ) {
+        sums.build(self);
+        next.resize_with(self.cells.len(), Vec::new);
+        self.write_next_rows(sums, 0, next, rule);
+    }
+
+    /// Overwrites `next` with the following generation of the rows starting at `first_row`,
+    /// using the already built `sums`.
+    fn write_next_rows(
+        &self,
+        sums: &SummedArea,
+        first_row: usize,
+        next: &mut [Vec<bool>],
+        rule: &impl Fn(bool, usize) -> bool,
+    ) {
+        // when nothing is born from nothing, a row with no live cells in or beside it stays dead
+        let skip_quiet_rows = !rule(false, 0);
+        let rows = self.cells[first_row..].iter().zip(next.iter_mut());
+        for (row_num, (row, next_row)) in (first_row..).zip(rows) {
+            next_row.clear();
+            if skip_quiet_rows && sums.is_quiet(row_num) {
+                next_row.resize(row.len(), false);
+                continue;
+            }
+            next_row.extend(row.iter().enumerate().map(|(col_num, &alive)| {
+                rule(
+                    alive,
+                    sums.count(self.neighborhood, row_num, col_num, alive),
+                )
+            }));
+        }
+    }
+
+    /// The coordinates of the neighbors of the cell at `row`, `col`, not including the cell
+    /// itself, or none if the cell is outside the board.
+    ///
+    /// Neighbors follow the board's [`Neighborhood`] and [`radius`](GameOfLife::radius), and its
+    /// [`BoundaryMode`] decides whether they wrap around the edges or stop at them. On a toroidal
+    /// board narrower than the neighborhood, the same cell can be a neighbor from more than one
+    /// side, and is then yielded once for each.
+    pub fn neighbor_coords(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (rows, cols) = self.dimensions();
+        let on_board = row < rows && col < cols;
+        let boundary = self.boundary;
+        self.neighborhood
+            .offsets(self.radius)
+            .filter(move |_| on_board)
+            .filter_map(move |(row_offset, col_offset)| {
+                // the boundary mode decides what, if anything, lies past each edge
+                boundary.resolve((row, col), (row_offset, col_offset), (rows, cols))
+            })
+    }
+
+    /// The number of living neighbors of the cell at `row_num`, `col_num`, not counting the cell
+    /// itself, or 0 if the cell is outside the board.
+    ///
+    /// This visits each of the [`neighbor_coords`](GameOfLife::neighbor_coords), so it is the
+    /// reference that the faster counting used when stepping the board must agree with.
+    pub fn count_living_neighbors(&self, row_num: usize, col_num: usize) -> usize {
+        self.neighbor_coords(row_num, col_num)
+            .filter(|&(neighbor_row_num, neighbor_col_num)| {
+                self.cells[neighbor_row_num][neighbor_col_num]
             })
             .count()
     }
 }
 
+/// Validates the rows as [`GameOfLife::new`] does.
+impl TryFrom<Vec<Vec<bool>>> for GameOfLife {
+    type Error = ShapeError;
+
+    fn try_from(rows: Vec<Vec<bool>>) -> Result<GameOfLife, ShapeError> {
+        GameOfLife::new(rows)
+    }
+}
+
+/// Parses text in the format written by [`Display`](fmt::Display): one line per row, with `•` for
+/// live cells and spaces for dead ones.
+///
+/// Any other character is an error. As with [`GameOfLife::from_lines`], short lines are padded
+/// with dead cells, and empty text is the empty board.
+impl TryFrom<&str> for GameOfLife {
+    type Error = ParseError;
+
+    fn try_from(text: &str) -> Result<GameOfLife, ParseError> {
+        let lines: Vec<&str> = text.lines().collect();
+        check_chars(&lines, |ch| ch == '•' || ch == ' ')?;
+        Ok(GameOfLife::from_lines(&lines))
+    }
+}
+
+/// Parses the same text as `GameOfLife::try_from`, so that boards work with [`str::parse`].
+impl FromStr for GameOfLife {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<GameOfLife, ParseError> {
+        GameOfLife::try_from(text)
+    }
+}
+
+/// Finds the first character in `lines` that isn't `allowed`.
+fn check_chars(lines: &[&str], allowed: impl Fn(char) -> bool) -> Result<(), ParseError> {
+    for (line_index, line) in lines.iter().enumerate() {
+        if let Some((col_index, ch)) = line.chars().enumerate().find(|&(_, ch)| !allowed(ch)) {
+            return Err(ParseError::UnexpectedChar {
+                line: line_index + 1,
+                col: col_index + 1,
+                ch,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Collects rows into a board.
+///
+/// # Panics
+///
+/// Panics if the rows are not all the same width; collect into a `Vec` and use
+/// [`GameOfLife::new`] to handle that case instead.
+impl FromIterator<Vec<bool>> for GameOfLife {
+    fn from_iter<I: IntoIterator<Item = Vec<bool>>>(rows: I) -> GameOfLife {
+        match GameOfLife::new(rows.into_iter().collect()) {
+            Ok(game) => game,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+impl From<GameOfLife> for Vec<Vec<bool>> {
+    fn from(game: GameOfLife) -> Vec<Vec<bool>> {
+        game.cells
+    }
+}
+
+/// Reads the cell at `(row, col)`, panicking if it is outside the board; see
+/// [`GameOfLife::get`] for a checked alternative.
+impl Index<(usize, usize)> for GameOfLife {
+    type Output = bool;
+
+    fn index(&self, (row, col): (usize, usize)) -> &bool {
+        &self.cells[row][col]
+    }
+}
+
+/// Renders live cells as `•` and dead cells as spaces, one line per row.
+///
+/// The output parses back to an equal board with [`GameOfLife::from_lines`].
+impl fmt::Display for GameOfLife {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (row_num, row) in self.cells().iter().enumerate() {
+            if row_num > 0 {
+                f.write_str("\n")?;
+            }
+            for &alive in row {
+                f.write_str(if alive { "•" } else { " " })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returned when a board's rows are not all the same width.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapeError {
+    /// Index of the first row whose width differs from the first row's.
+    pub row: usize,
+    /// Width of the offending row.
+    pub len: usize,
+    /// Width of the first row, which every row is expected to match.
+    pub expected: usize,
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "row {} has {} cells, expected {}",
+            self.row, self.len, self.expected
+        )
+    }
+}
+
+impl Error for ShapeError {}
+
+/// Returned when a flat buffer of cells doesn't hold exactly one entry per cell of the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferSizeError {
+    /// Length of the buffer.
+    pub len: usize,
+    /// Number of cells on the board, `rows * cols`.
+    pub expected: usize,
+}
+
+impl BufferSizeError {
+    /// Checks that a buffer of `len` entries fits a `rows` by `cols` board exactly.
+    pub(crate) fn check(rows: usize, cols: usize, len: usize) -> Result<(), BufferSizeError> {
+        match rows.checked_mul(cols) {
+            Some(expected) if expected == len => Ok(()),
+            expected => Err(BufferSizeError {
+                len,
+                expected: expected.unwrap_or(usize::MAX),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for BufferSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "buffer has {} cells, expected {}",
+            self.len, self.expected
+        )
+    }
+}
+
+impl Error for BufferSizeError {}
+
+/// Returned when a board doesn't fit the dimensions of a fixed-size representation, or a pattern
+/// doesn't fit on the board it's [centered on](GameOfLife::centered_on).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DimensionsError {
+    /// The board's dimensions, as `(rows, cols)`.
+    pub found: (usize, usize),
+    /// The required dimensions, or for a [`BitBoard8`] or a centered pattern the largest allowed,
+    /// as `(rows, cols)`.
+    pub expected: (usize, usize),
+}
+
+impl fmt::Display for DimensionsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "board is {}x{}, expected {}x{}",
+            self.found.0, self.found.1, self.expected.0, self.expected.1
+        )
+    }
+}
+
+impl Error for DimensionsError {}
+
+/// Returned when text cannot be parsed as a board. Lines and columns are numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A character other than the ones for live and dead cells.
+    UnexpectedChar { line: usize, col: usize, ch: char },
+    /// A line whose width differs from the first line's.
+    JaggedRows { line: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedChar { line, col, ch } => {
+                write!(
+                    f,
+                    "unexpected character {:?} at line {}, column {}",
+                    ch, line, col
+                )
+            }
+            ParseError::JaggedRows { line } => {
+                write!(f, "line {} is not as wide as the first line", line)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returned when an unbounded board is given a rule with births on 0 neighbors, such as
+/// `B03/S23`, under which all of the endless dead space around a pattern would come to life.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct B0RuleError {
+    /// The rule that was rejected.
+    pub rule: Rule,
+}
+
+#[cfg(feature = "std")]
+impl B0RuleError {
+    /// Checks that `rule` has no births on 0 neighbors.
+    pub(crate) fn check(rule: Rule) -> Result<(), B0RuleError> {
+        let (birth, _) = rule.to_bits();
+        if birth & 1 == 0 {
+            Ok(())
+        } else {
+            Err(B0RuleError { rule })
+        }
+    }
+}
+
+impl fmt::Display for B0RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "rule {} has births on 0 neighbors, which an unbounded board can't follow",
+            self.rule
+        )
+    }
+}
+
+impl Error for B0RuleError {}
+
+/// Returned when a cell position lies outside the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// Row of the requested cell.
+    pub row: usize,
+    /// Column of the requested cell.
+    pub col: usize,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cell ({}, {}) is outside the board", self.row, self.col)
+    }
+}
+
+impl Error for OutOfBounds {}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use super::*;
 
     #[test]
@@ -65,6 +909,64 @@ mod tests {
         assert_static(&new_game(&[]));
     }
 
+    #[test]
+    fn test_vec_conversions_round_trip() {
+        #[rustfmt::skip]
+        let beehive = new_game(&[
+            "      ",
+            "  ••  ",
+            " •  • ",
+            "  ••  ",
+            "      ",
+        ]);
+        let rows: Vec<Vec<bool>> = beehive.clone().into();
+        assert_eq!(GameOfLife::try_from(rows), Ok(beehive));
+        assert!(GameOfLife::try_from(vec![vec![true], vec![]]).is_err());
+    }
+
+    #[test]
+    fn test_empty_rows_are_static() {
+        assert_static(&GameOfLife::from_cells(vec![vec![], vec![]]));
+    }
+
+    #[test]
+    fn test_new_accepts_rectangular_rows() {
+        assert_eq!(
+            GameOfLife::new(vec![]).map(|game| game.cells().len()),
+            Ok(0)
+        );
+        assert_eq!(
+            GameOfLife::new(vec![vec![true, false], vec![false, true]]),
+            Ok(GameOfLife::from_cells(vec![
+                vec![true, false],
+                vec![false, true]
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_new_rejects_jagged_rows() {
+        assert_eq!(
+            GameOfLife::new(vec![vec![true, false], vec![false, true], vec![true]]),
+            Err(ShapeError {
+                row: 2,
+                len: 1,
+                expected: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_new_padded_fills_short_rows() {
+        let rows = vec![vec![true], vec![false, true, true], vec![]];
+        assert!(GameOfLife::new(rows.clone()).is_err());
+        assert_eq!(
+            GameOfLife::new_padded(rows),
+            new_game(&["•  ", " ••", "   "])
+        );
+        assert_eq!(GameOfLife::new_padded(vec![]), new_game(&[]));
+    }
+
     #[test]
     fn test_1x1_dead_is_static() {
         assert_static(&new_game(&[" "]));
@@ -164,13 +1066,494 @@ mod tests {
         assert_next_state(&off, &on);
     }
 
+    #[test]
+    fn test_from_lines_pads_short_rows() {
+        #[rustfmt::skip]
+        assert_eq!(
+            GameOfLife::from_lines(&[
+                "• ",
+                "",
+                " ••",
+            ]),
+            GameOfLife::from_cells(vec![
+                vec![true, false, false],
+                vec![false, false, false],
+                vec![false, true, true],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_from_lines_with_custom_alive_char() {
+        #[rustfmt::skip]
+        assert_eq!(
+            GameOfLife::from_lines_with(&[
+                "#.",
+                ".#",
+            ], '#'),
+            GameOfLife::from_cells(vec![vec![true, false], vec![false, true]])
+        );
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        assert_eq!(new_game(&[]).to_string(), "");
+
+        #[rustfmt::skip]
+        let beacon = new_game(&[
+            "      ",
+            " ••   ",
+            " •    ",
+            "    • ",
+            "   •• ",
+        ]);
+        let rendered = beacon.to_string();
+        assert_eq!(rendered, "      \n ••   \n •    \n    • \n   •• ");
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(GameOfLife::from_lines(&lines), beacon);
+    }
+
+    #[test]
+    fn test_dimensions_and_population() {
+        assert_eq!(new_game(&[]).dimensions(), (0, 0));
+        assert_eq!(new_game(&[]).population(), 0);
+
+        #[rustfmt::skip]
+        let beehive = new_game(&[
+            "      ",
+            "  ••  ",
+            " •  • ",
+            "  ••  ",
+            "      ",
+        ]);
+        assert_eq!(beehive.dimensions(), (5, 6));
+        assert_eq!(beehive.population(), 6);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        assert_eq!(new_game(&[]).bounding_box(), None);
+        assert_eq!(new_game(&["   ", "   "]).bounding_box(), None);
+
+        #[rustfmt::skip]
+        let game = new_game(&[
+            "      ",
+            "   •  ",
+            " •    ",
+            "    • ",
+            "      ",
+        ]);
+        assert_eq!(game.bounding_box(), Some((1, 1, 3, 4)));
+    }
+
+    #[test]
+    fn test_coords_round_trip() {
+        #[rustfmt::skip]
+        let game = new_game(&[
+            " • ",
+            "  •",
+            "•••",
+            "   ",
+        ]);
+        let live_cells = game.live_cells();
+        assert_eq!(live_cells, vec![(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)]);
+        assert_eq!(GameOfLife::from_coords(4, 3, &live_cells), Ok(game));
+        assert_eq!(
+            GameOfLife::from_coords(4, 3, &[(1, 1), (4, 0)]),
+            Err(OutOfBounds { row: 4, col: 0 })
+        );
+    }
+
+    #[test]
+    fn test_get_set_toggle() {
+        let mut game = new_game(&["  ", "  "]);
+        assert_eq!(game.get(1, 0), Some(false));
+        assert_eq!(game.set(1, 0, true), Ok(()));
+        assert_eq!(game.get(1, 0), Some(true));
+        assert_eq!(game.toggle(1, 0), Ok(()));
+        assert_eq!(game.toggle(0, 1), Ok(()));
+        assert_eq!(game, new_game(&[" •", "  "]));
+
+        assert_eq!(game.get(2, 0), None);
+        assert_eq!(game.get(0, 2), None);
+        assert_eq!(game.set(0, 2, true), Err(OutOfBounds { row: 0, col: 2 }));
+        assert_eq!(game.toggle(2, 0), Err(OutOfBounds { row: 2, col: 0 }));
+    }
+
+    #[test]
+    fn test_hash_set_detects_cycles() {
+        #[rustfmt::skip]
+        let beacon = new_game(&[
+            "      ",
+            " ••   ",
+            " ••   ",
+            "   •• ",
+            "   •• ",
+        ]);
+        let mut seen = HashSet::new();
+        let repeat = beacon
+            .generations()
+            .position(|game| !seen.insert(game))
+            .unwrap();
+        assert_eq!(repeat, 2);
+
+        // the scratch buffer left behind by step_mut is not part of the hash
+        let mut stepped = new_game(&["•••"]);
+        stepped.step_mut();
+        assert!(seen.insert(stepped.clone()));
+        assert!(!seen.insert(new_game(&[" • "])));
+    }
+
+    #[test]
+    fn test_next_state_with_custom_rule() {
+        #[rustfmt::skip]
+        let blinker = new_game(&[
+            "   ",
+            "•••",
+            "   ",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(blinker.next_state_with(|alive, _| !alive), new_game(&[
+            "•••",
+            "   ",
+            "•••",
+        ]));
+        #[rustfmt::skip]
+        assert_eq!(blinker.next_state_with(|alive, count| alive || count > 0), new_game(&[
+            "•••",
+            "•••",
+            "•••",
+        ]));
+    }
+
+    #[test]
+    fn test_step_mut_changed() {
+        let mut blinker = new_game(&["   ", "•••", "   "]);
+        assert!(blinker.step_mut_changed());
+        assert_eq!(blinker, new_game(&[" • ", " • ", " • "]));
+
+        let mut block = new_game(&["    ", " •• ", " •• ", "    "]);
+        assert!(!block.step_mut_changed());
+        assert_eq!(block, new_game(&["    ", " •• ", " •• ", "    "]));
+
+        let mut dying = new_game(&["•  ", "   ", "  •"]);
+        assert!(dying.step_mut_changed());
+        assert!(!dying.step_mut_changed());
+        assert!(dying.is_extinct());
+    }
+
+    #[test]
+    fn test_step_mut_matches_next_state() {
+        #[rustfmt::skip]
+        let mut game = new_game(&[
+            "      ",
+            " ••   ",
+            " ••   ",
+            "   •• ",
+            "   •• ",
+        ]);
+        let mut expected = game.clone();
+        for _ in 0..50 {
+            game.step_mut();
+            expected = expected.next_state();
+            assert_eq!(game, expected);
+        }
+    }
+
+    #[test]
+    fn test_auto_grow_keeps_a_glider_whole() {
+        let glider = patterns::glider();
+        let growing = glider.clone().with_auto_grow(true);
+        assert!(!glider.auto_grow() && growing.auto_grow());
+        assert_eq!((glider.growth(), growing.growth()), (0, 1));
+
+        let later = growing.nth_generation(40);
+        assert_eq!(later.trimmed(), growing);
+        assert_eq!(later.population(), 5);
+        assert!(later.dimensions() > (12, 12));
+        assert_eq!(
+            growing.next_state(),
+            growing.clone().generations().nth(1).unwrap()
+        );
+        // without growing, the glider runs into the edge and breaks up
+        assert_ne!(glider.nth_generation(40).population(), 5);
+
+        // a quiet middle doesn't grow the board, and a still life never touches the edge
+        let block = patterns::block().padded(1).with_auto_grow(true);
+        assert_eq!(block.growth(), 0);
+        assert_eq!(block.nth_generation(5), block);
+
+        // wider neighborhoods grow by their radius
+        let lone = GameOfLife::from_lines(&["   ", " • ", "   "])
+            .with_radius(2)
+            .with_auto_grow(true);
+        assert_eq!(lone.growth(), 2);
+        let grown = lone.next_state_with(|_, count| count > 0);
+        assert_eq!(grown.dimensions(), (7, 7));
+        assert_eq!(grown.population(), 24);
+    }
+
+    #[test]
+    fn test_summed_area_matches_neighbor_visits() {
+        fn assert_matches_neighbor_visits(game: &GameOfLife, generations: usize) {
+            let mut game = game.clone();
+            for _ in 0..generations {
+                let (rows, cols) = game.dimensions();
+                let visited = game.with_cells(
+                    (0..rows)
+                        .map(|row| {
+                            (0..cols)
+                                .map(|col| {
+                                    let count = game.count_living_neighbors(row, col);
+                                    game.rule.is_alive_next(game.cells[row][col], count)
+                                })
+                                .collect()
+                        })
+                        .collect(),
+                );
+                game = game.next_state();
+                assert_eq!(game, visited);
+            }
+        }
+
+        let mut rng = SplitMix64::new(28);
+        assert_matches_neighbor_visits(&GameOfLife::random(200, 200, 0.4, &mut rng), 5);
+        for &boundary in &[
+            BoundaryMode::Dead,
+            BoundaryMode::Toroidal,
+            BoundaryMode::Mirror,
+            BoundaryMode::KleinBottle,
+        ] {
+            for &neighborhood in &[Neighborhood::Moore, Neighborhood::VonNeumann] {
+                for &(rows, cols) in &[(1, 1), (1, 7), (2, 2), (3, 5), (17, 23)] {
+                    let game = GameOfLife::random(rows, cols, 0.5, &mut rng)
+                        .with_boundary(boundary)
+                        .with_neighborhood(neighborhood);
+                    assert_matches_neighbor_visits(&game, 5);
+                }
+                for radius in 0..=3 {
+                    let game = GameOfLife::random(9, 12, 0.15, &mut rng)
+                        .with_boundary(boundary)
+                        .with_neighborhood(neighborhood)
+                        .with_radius(radius);
+                    assert_matches_neighbor_visits(&game, 5);
+                }
+            }
+            // mostly empty boards, where whole rows are skipped
+            for &(at_row, at_col) in &[(0, 0), (8, 17), (17, 8), (18, 18)] {
+                let mut game = GameOfLife::from_cells(vec![vec![false; 20]; 20]);
+                game.overlay(&patterns::glider(), at_row, at_col);
+                assert_matches_neighbor_visits(&game.with_boundary(boundary), 12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_state_skips_quiet_rows() {
+        let mut game = GameOfLife::from_cells(vec![vec![false; 200]; 200]);
+        game.overlay(&patterns::glider(), 100, 100);
+        let visits = Cell::new(0);
+        let next = game.next_state_with(|alive, count| {
+            visits.set(visits.get() + 1);
+            Rule::conway().is_alive_next(alive, count)
+        });
+        assert_eq!(next, game.next_state());
+        // only the glider's 3 rows and the rows either side of them are visited, plus the check
+        // for births from nothing
+        assert_eq!(visits.get(), 5 * 200 + 1);
+
+        let empty = GameOfLife::dead(3, 3);
+        let b0 = empty.clone().with_rule(Rule::parse("B0/S").unwrap());
+        assert_eq!(b0.next_state().population(), 9);
+        assert_eq!(empty.next_state().population(), 0);
+    }
+
+    #[test]
+    fn test_index() {
+        let game = new_game(&["• ", " •"]);
+        assert!(game[(0, 0)]);
+        assert!(!game[(0, 1)]);
+        assert!(game[(1, 1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let _ = new_game(&["• "])[(0, 2)];
+    }
+
+    #[test]
+    fn test_collect_rows() {
+        let game: GameOfLife = ["• ", " •"]
+            .iter()
+            .map(|line| line.chars().map(|char| char == '•').collect())
+            .collect();
+        assert_eq!(game, new_game(&["• ", " •"]));
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has 1 cells, expected 2")]
+    fn test_collect_ragged_rows() {
+        let _: GameOfLife = vec![vec![true, false], vec![true]].into_iter().collect();
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let game = new_game(&["• ", " •", "  "]);
+        assert_eq!(GameOfLife::try_from(game.to_string().as_str()), Ok(game));
+        assert_eq!(GameOfLife::try_from("•\n••\n"), Ok(new_game(&["• ", "••"])));
+        assert_eq!(GameOfLife::try_from(""), Ok(new_game(&[])));
+        assert_eq!("• \n •".parse(), Ok(new_game(&["• ", " •"])));
+        assert_eq!("   \n   ".parse(), Ok(GameOfLife::dead(2, 3)));
+        assert_eq!(
+            GameOfLife::try_from("••\n•x"),
+            Err(ParseError::UnexpectedChar {
+                line: 2,
+                col: 2,
+                ch: 'x',
+            })
+        );
+    }
+
+    #[test]
+    fn test_population_in() {
+        #[rustfmt::skip]
+        let game = new_game(&[
+            "•• ",
+            " ••",
+            "•  ",
+        ]);
+        assert_eq!(game.population_in(0, 0, 2, 2), 5);
+        assert_eq!(game.population_in(1, 1, 1, 2), 2);
+        assert_eq!(game.population_in(0, 1, 0, 1), 1);
+        assert_eq!(game.population_in(1, 0, 10, 10), 3);
+        assert_eq!(game.population_in(0, 0, usize::MAX, usize::MAX), 5);
+        assert_eq!(game.population_in(2, 0, 1, 2), 0);
+        assert_eq!(game.population_in(0, 2, 2, 1), 0);
+    }
+
+    #[test]
+    fn test_is_extinct() {
+        assert!(new_game(&[]).is_extinct());
+        assert!(new_game(&["   ", "   "]).is_extinct());
+        assert!(!new_game(&["   ", "  •"]).is_extinct());
+    }
+
+    #[test]
+    fn test_neighbor_coords() {
+        let game = new_game(&["   ", "   ", "   "]);
+        assert_eq!(game.neighbor_coords(1, 1).count(), 8);
+        assert_eq!(
+            game.neighbor_coords(0, 0).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0), (1, 1)]
+        );
+        assert_eq!(game.neighbor_coords(3, 0).count(), 0);
+
+        let torus = game.clone().with_boundary(BoundaryMode::Toroidal);
+        let mut coords: Vec<_> = torus.neighbor_coords(0, 0).collect();
+        coords.sort_unstable();
+        assert_eq!(
+            coords,
+            vec![
+                (0, 1),
+                (0, 2),
+                (1, 0),
+                (1, 1),
+                (1, 2),
+                (2, 0),
+                (2, 1),
+                (2, 2)
+            ]
+        );
+
+        let von_neumann = game.with_neighborhood(Neighborhood::VonNeumann);
+        assert_eq!(
+            von_neumann.neighbor_coords(2, 1).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 0), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn test_count_living_neighbors() {
+        #[rustfmt::skip]
+        let game = new_game(&[
+            "•• ",
+            "•••",
+        ]);
+        assert_eq!(game.count_living_neighbors(0, 0), 3);
+        assert_eq!(game.count_living_neighbors(1, 1), 4);
+        assert_eq!(game.count_living_neighbors(0, 2), 3);
+        assert_eq!(game.count_living_neighbors(2, 0), 0);
+        assert_eq!(game.count_living_neighbors(0, 7), 0);
+    }
+
+    #[test]
+    fn test_flat_round_trips() {
+        let game = new_game(&["• •", " • "]);
+        assert_eq!(game.to_flat(), vec![1, 0, 1, 0, 1, 0]);
+        assert_eq!(GameOfLife::from_flat(2, 3, &game.to_flat()), Ok(game));
+        assert_eq!(
+            GameOfLife::from_flat(2, 2, &[0, 7, 1, 0]),
+            Ok(new_game(&[" •", "• "]))
+        );
+        assert_eq!(
+            GameOfLife::from_flat(3, 2, &[0; 5]),
+            Err(BufferSizeError {
+                len: 5,
+                expected: 6,
+            })
+        );
+        assert_eq!(
+            GameOfLife::from_flat(usize::MAX, 2, &[]),
+            Err(BufferSizeError {
+                len: 0,
+                expected: usize::MAX,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        assert_eq!(
+            GameOfLife::parse_strict("O..\n.O.\r\n", 'O', '.'),
+            Ok(new_game(&["•  ", " • "]))
+        );
+        assert_eq!(GameOfLife::parse_strict("", 'O', '.'), Ok(new_game(&[])));
+        assert_eq!(
+            GameOfLife::parse_strict("O..\n.O*", 'O', '.'),
+            Err(ParseError::UnexpectedChar {
+                line: 2,
+                col: 3,
+                ch: '*',
+            })
+        );
+        assert_eq!(
+            GameOfLife::parse_strict("O..\n...\n.O", 'O', '.'),
+            Err(ParseError::JaggedRows { line: 3 })
+        );
+        assert_eq!(
+            ParseError::JaggedRows { line: 3 }.to_string(),
+            "line 3 is not as wide as the first line"
+        );
+    }
+
+    #[test]
+    fn test_render_with() {
+        let game = new_game(&["• ", " •", "  "]);
+        assert_eq!(game.render_with('#', '.'), "#.\n.#\n..");
+        assert_eq!(game.render_with('•', ' '), game.to_string());
+        assert_eq!(
+            GameOfLife::parse_strict(&game.render_with('█', ' '), '█', ' '),
+            Ok(game.clone())
+        );
+        let lines = game.render_with('O', '.');
+        let lines: Vec<&str> = lines.lines().collect();
+        assert_eq!(GameOfLife::from_lines_with(&lines, 'O'), game);
+        assert_eq!(new_game(&[]).render_with('#', '.'), "");
+    }
+
     fn new_game(initial_state: &[&str]) -> GameOfLife {
-        GameOfLife(
-            initial_state
-                .iter()
-                .map(|row| row.chars().map(|char| char != ' ').collect())
-                .collect(),
-        )
+        GameOfLife::from_lines(initial_state)
     }
 
     fn assert_next_state(current: &GameOfLife, expected_next: &GameOfLife) {
@@ -1,3 +1,294 @@
+//! `life`, which plays a pattern in the terminal.
+//!
+//! ```text
+//! life [PATTERN] [--fps N] [--generations N] [--size ROWSxCOLS] [--wrap]
+//!      [--alive CHAR] [--dead CHAR]
+//! ```
+//!
+//! The pattern is read from the file `PATTERN`, or from standard input if it is left out or is
+//! `-`, in RLE, plaintext or Life 1.06 format. The animation runs until the board stops
+//! changing, `--generations` frames have been shown, or it is interrupted with Ctrl-C.
+
+use std::env;
+use std::io::{self, Write};
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use game_of_life::{BoundaryMode, GameOfLife};
+
+const USAGE: &str = "usage: life [PATTERN] [--fps N] [--generations N] [--size ROWSxCOLS] \
+    [--wrap] [--alive CHAR] [--dead CHAR]";
+
+/// Clears the terminal and moves the cursor to its top-left corner.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+/// The dead cells left around the pattern when no `--size` is given.
+const MARGIN: usize = 10;
+
+/// The longest the animation sleeps before checking for Ctrl-C again.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, PartialEq)]
+struct Options {
+    /// The pattern file, or `None` for standard input.
+    pattern: Option<String>,
+    fps: u32,
+    generations: Option<usize>,
+    /// The board's `(rows, cols)`, or `None` to fit the pattern with a margin around it.
+    size: Option<(usize, usize)>,
+    wrap: bool,
+    alive: char,
+    dead: char,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            pattern: None,
+            fps: 10,
+            generations: None,
+            size: None,
+            wrap: false,
+            alive: '•',
+            dead: ' ',
+        }
+    }
+}
+
+impl Options {
+    /// Parses the command-line arguments, not including the program name.
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or(format!("{} needs a value", name));
+            match arg.as_str() {
+                "--fps" => {
+                    options.fps = match value("--fps")?.parse() {
+                        Ok(fps) if fps > 0 => fps,
+                        _ => return Err("--fps must be a positive whole number".to_string()),
+                    }
+                }
+                "--generations" => {
+                    let generations = value("--generations")?;
+                    options.generations = Some(generations.parse().map_err(|_| {
+                        format!(
+                            "--generations must be a whole number, not {:?}",
+                            generations
+                        )
+                    })?);
+                }
+                "--size" => options.size = Some(parse_size(&value("--size")?)?),
+                "--wrap" => options.wrap = true,
+                "--alive" => options.alive = parse_glyph("--alive", &value("--alive")?)?,
+                "--dead" => options.dead = parse_glyph("--dead", &value("--dead")?)?,
+                "-" if options.pattern.is_none() => options.pattern = None,
+                _ if arg.starts_with("--") => return Err(format!("unknown option {}", arg)),
+                _ if options.pattern.is_none() => options.pattern = Some(arg),
+                _ => return Err(format!("unexpected argument {:?}", arg)),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Parses a board size such as `40x80`, in rows then columns.
+fn parse_size(size: &str) -> Result<(usize, usize), String> {
+    let invalid = || format!("--size must look like 40x80, not {:?}", size);
+    let (rows, cols) = size.split_once('x').ok_or_else(invalid)?;
+    Ok((
+        rows.parse().map_err(|_| invalid())?,
+        cols.parse().map_err(|_| invalid())?,
+    ))
+}
+
+fn parse_glyph(name: &str, glyph: &str) -> Result<char, String> {
+    let mut chars = glyph.chars();
+    match (chars.next(), chars.next()) {
+        (Some(glyph), None) => Ok(glyph),
+        _ => Err(format!("{} must be a single character", name)),
+    }
+}
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Arranges for Ctrl-C to set [`INTERRUPTED`] instead of killing the process, so the animation
+/// can stop between frames and leave the terminal as it found it.
+#[cfg(unix)]
+fn catch_interrupts() {
+    extern "C" fn on_interrupt(_signal: i32) {
+        INTERRUPTED.store(true, Ordering::SeqCst);
+    }
+    extern "C" {
+        fn signal(signal: i32, handler: extern "C" fn(i32)) -> usize;
+    }
+    const SIGINT: i32 = 2;
+    // the handler only stores to an atomic, which is safe to do from a signal handler
+    unsafe {
+        signal(SIGINT, on_interrupt);
+    }
+}
+
+/// Elsewhere, Ctrl-C ends the process as usual.
+#[cfg(not(unix))]
+fn catch_interrupts() {}
+
+/// Sleeps until `deadline`, returning early with `false` if Ctrl-C is pressed.
+fn sleep_until(deadline: Instant) -> bool {
+    loop {
+        if INTERRUPTED.load(Ordering::SeqCst) {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        thread::sleep((deadline - now).min(POLL_INTERVAL));
+    }
+}
+
+fn play(game: &mut GameOfLife, options: &Options, out: &mut impl Write) -> io::Result<()> {
+    let delay = Duration::from_secs(1) / options.fps;
+    let mut deadline = Instant::now();
+    let mut frame = 0;
+    while options
+        .generations
+        .is_none_or(|generations| frame < generations)
+    {
+        if frame > 0 && !sleep_until(deadline) {
+            break;
+        }
+        deadline += delay;
+        writeln!(
+            out,
+            "{}{}",
+            CLEAR_SCREEN,
+            game.render_with(options.alive, options.dead)
+        )?;
+        writeln!(
+            out,
+            "generation {}, population {}",
+            frame,
+            game.population()
+        )?;
+        out.flush()?;
+        if !game.step_mut_changed() {
+            break;
+        }
+        frame += 1;
+    }
+    Ok(())
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let pattern = match &options.pattern {
+        Some(path) => GameOfLife::from_file(path).map_err(|err| format!("{}: {}", path, err))?,
+        None => GameOfLife::read_pattern(io::stdin().lock())
+            .map_err(|err| format!("standard input: {}", err))?,
+    };
+    let mut game = match options.size {
+        Some((rows, cols)) => pattern.centered_on(rows, cols).map_err(|err| {
+            let (pattern_rows, pattern_cols) = err.found;
+            format!(
+                "the {}x{} pattern doesn't fit on a {}x{} board",
+                pattern_rows, pattern_cols, rows, cols
+            )
+        })?,
+        None => pattern.trimmed().padded(MARGIN),
+    };
+    if options.wrap {
+        game = game.with_boundary(BoundaryMode::Toroidal);
+    }
+
+    catch_interrupts();
+    let mut out = io::stdout().lock();
+    write!(out, "{}", HIDE_CURSOR).map_err(|err| err.to_string())?;
+    let played = play(&mut game, options, &mut out);
+    let restored = write!(out, "{}", SHOW_CURSOR).and_then(|_| out.flush());
+    played.and(restored).map_err(|err| err.to_string())
+}
+
 fn main() {
-    println!("Hello, world!");
+    let options = Options::parse(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("life: {}\n{}", err, USAGE);
+        process::exit(2);
+    });
+    if let Err(err) = run(&options) {
+        eprintln!("life: {}", err);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_options() {
+        assert_eq!(parse(&[]), Ok(Options::default()));
+        assert_eq!(
+            parse(&[
+                "glider.rle",
+                "--fps",
+                "15",
+                "--generations",
+                "500",
+                "--wrap",
+                "--size",
+                "40x80",
+                "--alive",
+                "#",
+                "--dead",
+                ".",
+            ]),
+            Ok(Options {
+                pattern: Some("glider.rle".to_string()),
+                fps: 15,
+                generations: Some(500),
+                size: Some((40, 80)),
+                wrap: true,
+                alive: '#',
+                dead: '.',
+            })
+        );
+        assert_eq!(parse(&["-"]), Ok(Options::default()));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_options() {
+        assert!(parse(&["--fps", "0"]).is_err());
+        assert!(parse(&["--fps"]).is_err());
+        assert!(parse(&["--size", "40"]).is_err());
+        assert!(parse(&["--alive", "##"]).is_err());
+        assert!(parse(&["--speed", "2"]).is_err());
+        assert!(parse(&["a.rle", "b.rle"]).is_err());
+    }
+
+    #[test]
+    fn test_play_stops_when_static() {
+        let mut block = GameOfLife::from_lines(&["    ", " •• ", " •• ", "    "]);
+        let options = Options {
+            fps: 1000,
+            alive: 'O',
+            dead: '.',
+            ..Options::default()
+        };
+        let mut out = Vec::new();
+        play(&mut block, &options, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!(
+                "{}....\n.OO.\n.OO.\n....\ngeneration 0, population 4\n",
+                CLEAR_SCREEN
+            )
+        );
+    }
 }
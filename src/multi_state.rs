@@ -0,0 +1,290 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{GameOfLife, Rule, RuleError, ShapeError};
+
+/// A rule from the Generations family, such as Brian's Brain, `B2/S/3`.
+///
+/// Cells have `states` states: 0 is dead, 1 is alive and the rest are dying. A dead cell is born
+/// and a live cell survives according to the life-like birth and survival counts, where only live
+/// cells count as neighbors. A live cell that doesn't survive starts dying instead, and dying
+/// cells advance one state per generation until they wrap around to dead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GenerationsRule {
+    rule: Rule,
+    states: u8,
+}
+
+impl GenerationsRule {
+    /// Brian's Brain, `B2/S/3`.
+    pub fn brians_brain() -> GenerationsRule {
+        GenerationsRule {
+            rule: Rule::parse("B2/S").unwrap(),
+            states: 3,
+        }
+    }
+
+    /// Star Wars, `B2/S345/4`.
+    pub fn star_wars() -> GenerationsRule {
+        GenerationsRule {
+            rule: Rule::parse("B2/S345").unwrap(),
+            states: 4,
+        }
+    }
+
+    /// Parses a rulestring such as `B2/S/3`: a life-like `B/S` rule followed by the number of
+    /// states, which must be between 2 and 255.
+    ///
+    /// Golly's older notation, with the survival counts first and no letters, as in `/2/3` or
+    /// `345/2/4`, is accepted too.
+    pub fn parse(rulestring: &str) -> Result<GenerationsRule, RuleError> {
+        let rulestring = rulestring.trim();
+        let split = rulestring.rfind('/').ok_or(RuleError::Malformed)?;
+        let states: u32 = rulestring[split + 1..]
+            .parse()
+            .map_err(|_| RuleError::Malformed)?;
+        if !(2..=255).contains(&states) {
+            return Err(RuleError::StatesOutOfRange(states));
+        }
+        let counts = &rulestring[..split];
+        let rule = if counts.starts_with(['B', 'b']) {
+            Rule::parse(counts)?
+        } else {
+            let (survival, birth) = counts.split_once('/').ok_or(RuleError::Malformed)?;
+            Rule::parse(&format!("B{}/S{}", birth, survival))?
+        };
+        Ok(GenerationsRule {
+            rule,
+            states: states as u8,
+        })
+    }
+
+    /// The number of cell states, including dead and alive.
+    pub fn states(&self) -> u8 {
+        self.states
+    }
+
+    /// The state a cell in `state` moves to, given its number of live neighbors.
+    fn next(&self, state: u8, living_neighbor_count: usize) -> u8 {
+        match state {
+            0 if self.rule.is_alive_next(false, living_neighbor_count) => 1,
+            0 => 0,
+            1 if self.rule.is_alive_next(true, living_neighbor_count) => 1,
+            dying => (dying as usize + 1) as u8 % self.states,
+        }
+    }
+}
+
+/// A two-state rule behaves exactly like the life-like rule it extends.
+impl From<Rule> for GenerationsRule {
+    fn from(rule: Rule) -> GenerationsRule {
+        GenerationsRule { rule, states: 2 }
+    }
+}
+
+/// Renders the rule in `B<counts>/S<counts>/<states>` notation.
+impl fmt::Display for GenerationsRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.rule, self.states)
+    }
+}
+
+/// A board for [`GenerationsRule`]s, where each cell holds a state rather than just alive or dead.
+///
+/// Cells beyond the edges are always dead and neighbors are always the Moore neighborhood.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MultiStateLife {
+    cells: Vec<Vec<u8>>,
+    rule: GenerationsRule,
+}
+
+impl MultiStateLife {
+    /// Creates a board from rows of cell states, which must all be the same width.
+    ///
+    /// Any state at or above `rule.states()` is treated as the last dying state.
+    pub fn new(rows: Vec<Vec<u8>>, rule: GenerationsRule) -> Result<MultiStateLife, ShapeError> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((row, bad_row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(ShapeError {
+                row,
+                len: bad_row.len(),
+                expected: width,
+            });
+        }
+        Ok(MultiStateLife { cells: rows, rule })
+    }
+
+    /// The board's cell states, indexed as `cells()[row][col]`.
+    pub fn cells(&self) -> &Vec<Vec<u8>> {
+        &self.cells
+    }
+
+    /// The rule this board evolves under.
+    pub fn rule(&self) -> GenerationsRule {
+        self.rule
+    }
+
+    /// Computes the following generation, leaving this board unchanged.
+    pub fn next_state(&self) -> MultiStateLife {
+        let cells = (0..self.cells.len())
+            .map(|row_num| {
+                (0..self.cells[row_num].len())
+                    .map(|col_num| {
+                        let state = self.cells[row_num][col_num].min(self.rule.states - 1);
+                        self.rule
+                            .next(state, self.count_living_neighbors(row_num, col_num))
+                    })
+                    .collect()
+            })
+            .collect();
+        MultiStateLife {
+            cells,
+            rule: self.rule,
+        }
+    }
+
+    /// Renders this board as text, one line per row, drawing a cell in state `n` as `glyphs[n]`.
+    ///
+    /// States past the end of `glyphs` use its last glyph, so `[' ', '•', '·']` draws every dying
+    /// state alike. With no glyphs at all, every cell is a space.
+    pub fn render(&self, glyphs: &[char]) -> String {
+        let glyph = |state: u8| {
+            glyphs
+                .get(state as usize)
+                .or(glyphs.last())
+                .copied()
+                .unwrap_or(' ')
+        };
+        let lines: Vec<String> = self
+            .cells
+            .iter()
+            .map(|row| row.iter().map(|&state| glyph(state)).collect())
+            .collect();
+        lines.join("\n")
+    }
+
+    fn count_living_neighbors(&self, row_num: usize, col_num: usize) -> usize {
+        let rows = row_num.saturating_sub(1)..(row_num + 2).min(self.cells.len());
+        rows.flat_map(|neighbor_row_num| {
+            let neighbor_row = &self.cells[neighbor_row_num];
+            let cols = col_num.saturating_sub(1)..(col_num + 2).min(neighbor_row.len());
+            cols.filter(move |&neighbor_col_num| {
+                (neighbor_row_num, neighbor_col_num) != (row_num, col_num)
+                    && neighbor_row[neighbor_col_num] == 1
+            })
+        })
+        .count()
+    }
+}
+
+/// Renders dead cells as spaces, live cells as `•` and dying cells as the digit of their state,
+/// or `+` from state 10 on.
+impl fmt::Display for MultiStateLife {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.render(&[' ', '•', '2', '3', '4', '5', '6', '7', '8', '9', '+']))
+    }
+}
+
+/// Live cells become state 1 on a two-state board under the same life-like rule.
+impl From<&GameOfLife> for MultiStateLife {
+    fn from(game: &GameOfLife) -> MultiStateLife {
+        let cells = game
+            .cells()
+            .iter()
+            .map(|row| row.iter().map(|&alive| alive as u8).collect())
+            .collect();
+        MultiStateLife {
+            cells,
+            rule: game.rule().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            GenerationsRule::parse("B2/S/3"),
+            Ok(GenerationsRule::brians_brain())
+        );
+        assert_eq!(
+            GenerationsRule::parse("B2/S345/4").map(|rule| rule.to_string()),
+            Ok("B2/S345/4".to_string())
+        );
+        assert_eq!(GenerationsRule::parse("B2/S"), Err(RuleError::Malformed));
+        assert_eq!(
+            GenerationsRule::parse("B2/S/1"),
+            Err(RuleError::StatesOutOfRange(1))
+        );
+        assert_eq!(
+            GenerationsRule::parse("B9/S/3"),
+            Err(RuleError::CountOutOfRange(9))
+        );
+    }
+
+    #[test]
+    fn test_parse_golly_notation() {
+        assert_eq!(
+            GenerationsRule::parse("/2/3"),
+            Ok(GenerationsRule::brians_brain())
+        );
+        assert_eq!(
+            GenerationsRule::parse("345/2/4"),
+            Ok(GenerationsRule::star_wars())
+        );
+        assert_eq!(
+            GenerationsRule::parse("B2/S345/4"),
+            Ok(GenerationsRule::star_wars())
+        );
+        assert_eq!(GenerationsRule::parse("345/4"), Err(RuleError::Malformed));
+    }
+
+    #[test]
+    fn test_render() {
+        let game = MultiStateLife::new(
+            vec![vec![0, 1, 2], vec![3, 12, 0]],
+            GenerationsRule::parse("B2/S/13").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(game.to_string(), " •2\n3+ ");
+        assert_eq!(game.render(&['.', '#', '-']), ".#-\n--.");
+        assert_eq!(game.render(&[]), "   \n   ");
+    }
+
+    #[test]
+    fn test_brians_brain() {
+        let rule = GenerationsRule::brians_brain();
+        let game = MultiStateLife::new(
+            vec![vec![0, 0, 0, 0], vec![0, 1, 1, 0], vec![0, 0, 0, 0]],
+            rule,
+        )
+        .unwrap();
+        let next = game.next_state();
+        assert_eq!(
+            next.cells(),
+            &vec![vec![0, 1, 1, 0], vec![0, 2, 2, 0], vec![0, 1, 1, 0]]
+        );
+        // dying cells become dead and live cells start dying, while the cells beside the dying
+        // pair each see exactly two live neighbors
+        assert_eq!(
+            next.next_state().cells(),
+            &vec![vec![0, 2, 2, 0], vec![1, 0, 0, 1], vec![0, 2, 2, 0]]
+        );
+    }
+
+    #[test]
+    fn test_two_states_match_game_of_life() {
+        let game = patterns::glider().padded(3);
+        let mut multi_state = MultiStateLife::from(&game);
+        for expected in game.generations().take(12) {
+            assert_eq!(multi_state, MultiStateLife::from(&expected));
+            multi_state = multi_state.next_state();
+        }
+    }
+}
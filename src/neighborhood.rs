@@ -0,0 +1,94 @@
+/// Which surrounding cells count as a cell's neighbors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Neighborhood {
+    /// The eight cells orthogonally or diagonally adjacent.
+    #[default]
+    Moore,
+    /// The four cells orthogonally adjacent.
+    VonNeumann,
+}
+
+impl Neighborhood {
+    /// The `(row, col)` offsets of each neighbor within `radius` of the cell itself.
+    ///
+    /// The Moore neighborhood covers the square of cells up to `radius` away in both directions,
+    /// and the von Neumann neighborhood the diamond of cells up to `radius` steps away
+    /// orthogonally.
+    pub(crate) fn offsets(self, radius: usize) -> impl Iterator<Item = (isize, isize)> {
+        let radius = radius as isize;
+        (-radius..=radius)
+            .flat_map(move |row_offset| {
+                (-radius..=radius).map(move |col_offset| (row_offset, col_offset))
+            })
+            .filter(move |&(row_offset, col_offset)| {
+                (row_offset, col_offset) != (0, 0)
+                    && match self {
+                        Neighborhood::Moore => true,
+                        Neighborhood::VonNeumann => row_offset.abs() + col_offset.abs() <= radius,
+                    }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameOfLife;
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Neighborhood::Moore.offsets(1).count(), 8);
+        assert_eq!(
+            Neighborhood::VonNeumann.offsets(1).collect::<Vec<_>>(),
+            vec![(-1, 0), (0, -1), (0, 1), (1, 0)]
+        );
+        assert_eq!(Neighborhood::Moore.offsets(2).count(), 24);
+        assert_eq!(Neighborhood::VonNeumann.offsets(2).count(), 12);
+        assert_eq!(Neighborhood::Moore.offsets(0).count(), 0);
+    }
+
+    #[test]
+    fn test_von_neumann_ignores_diagonals() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            "• •",
+            "   ",
+            "•  ",
+        ]);
+        assert!(game.next_state().cells()[1][1]);
+
+        let von_neumann = game.with_neighborhood(Neighborhood::VonNeumann);
+        assert_eq!(von_neumann.neighborhood(), Neighborhood::VonNeumann);
+        assert!(!von_neumann.next_state().cells()[1][1]);
+    }
+
+    #[test]
+    fn test_radius_widens_neighborhood() {
+        let full = GameOfLife::from_lines(&["•••••"; 5]);
+        assert_eq!(full.radius(), 1);
+        assert_eq!(full.count_living_neighbors(2, 2), 8);
+
+        let wide = full.with_radius(2);
+        assert_eq!(wide.count_living_neighbors(2, 2), 24);
+        assert_eq!(wide.count_living_neighbors(0, 0), 8);
+        let diamond = wide.clone().with_neighborhood(Neighborhood::VonNeumann);
+        assert_eq!(diamond.count_living_neighbors(2, 2), 12);
+
+        // a Larger than Life style rule, with survival on 20 to 24 living neighbors
+        let next = wide.next_state_with(|alive, count| alive && count >= 20);
+        assert_eq!(next.population(), 1);
+        assert!(next.cells()[2][2]);
+    }
+
+    #[test]
+    fn test_radius_0_counts_no_neighbors() {
+        let full = GameOfLife::from_lines(&["•••"; 3]).with_radius(0);
+        assert_eq!(full.count_living_neighbors(1, 1), 0);
+        assert!(full.next_state().is_extinct());
+
+        let no_cols = GameOfLife::new(vec![vec![], vec![]])
+            .unwrap()
+            .with_radius(0);
+        assert_eq!(no_cols.next_state(), no_cols);
+    }
+}
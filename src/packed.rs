@@ -0,0 +1,219 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{GameOfLife, Rule};
+
+const WORD_BITS: usize = 64;
+
+/// A board that packs 64 cells into each word, using an eighth of the memory of [`GameOfLife`].
+///
+/// Each generation is computed a whole word at a time with bitwise operations. Cells beyond the
+/// edges are always dead and neighbors are always the Moore neighborhood.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackedLife {
+    rows: usize,
+    cols: usize,
+    words_per_row: usize,
+    /// Row-major words, where bit `col % 64` of word `col / 64` in a row holds cell `col`.
+    words: Vec<u64>,
+    rule: Rule,
+}
+
+impl PackedLife {
+    /// The board's size as `(rows, cols)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// The rule this board evolves under.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Whether the cell at `row`, `col` is alive, or `None` if it is outside the board.
+    pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+        if row < self.rows && col < self.cols {
+            let word = self.words[row * self.words_per_row + col / WORD_BITS];
+            Some(word >> (col % WORD_BITS) & 1 == 1)
+        } else {
+            None
+        }
+    }
+
+    /// The number of living cells.
+    pub fn population(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Computes the following generation, leaving this board unchanged.
+    pub fn next_state(&self) -> PackedLife {
+        let mut words = vec![0; self.words.len()];
+        for row in 0..self.rows {
+            for word_num in 0..self.words_per_row {
+                let mut counts = [0; 4];
+                for &neighbor_row in &[row.wrapping_sub(1), row, row + 1] {
+                    let (west, center, east) = self.shifted_words(neighbor_row, word_num);
+                    add_to_counts(&mut counts, west);
+                    add_to_counts(&mut counts, east);
+                    if neighbor_row != row {
+                        add_to_counts(&mut counts, center);
+                    }
+                }
+                let alive = self.word(row, word_num);
+                words[row * self.words_per_row + word_num] =
+                    next_bits(self.rule, &counts, alive) & self.word_mask(word_num);
+            }
+        }
+        PackedLife { words, ..*self }
+    }
+
+    fn word(&self, row: usize, word_num: usize) -> u64 {
+        if row < self.rows && word_num < self.words_per_row {
+            self.words[row * self.words_per_row + word_num]
+        } else {
+            0
+        }
+    }
+
+    /// The word of `row` at `word_num`, along with copies of it shifted so that each bit holds
+    /// the cell to its west or east.
+    fn shifted_words(&self, row: usize, word_num: usize) -> (u64, u64, u64) {
+        let center = self.word(row, word_num);
+        let previous = word_num
+            .checked_sub(1)
+            .map_or(0, |previous| self.word(row, previous));
+        let next = self.word(row, word_num + 1);
+        let west = center << 1 | previous >> (WORD_BITS - 1);
+        let east = center >> 1 | next << (WORD_BITS - 1);
+        (west, center, east)
+    }
+
+    /// The bits of the word at `word_num` that hold cells rather than padding.
+    fn word_mask(&self, word_num: usize) -> u64 {
+        let used_bits = self.cols - word_num * WORD_BITS;
+        if used_bits >= WORD_BITS {
+            !0
+        } else {
+            (1 << used_bits) - 1
+        }
+    }
+}
+
+/// Applies `rule` to every bit of `alive` at once, given the bits' living neighbor counts.
+pub(crate) fn next_bits(rule: Rule, counts: &[u64; 4], alive: u64) -> u64 {
+    let (mut born, mut survives) = (0, 0);
+    for count in 0..=8 {
+        let has_count = counts_equal(counts, count);
+        if rule.is_alive_next(false, count) {
+            born |= has_count;
+        }
+        if rule.is_alive_next(true, count) {
+            survives |= has_count;
+        }
+    }
+    !alive & born | alive & survives
+}
+
+/// Adds the bits of `word` to per-bit counters stored as four bit planes, least significant first.
+pub(crate) fn add_to_counts(counts: &mut [u64; 4], word: u64) {
+    let mut carry = word;
+    for plane in counts.iter_mut() {
+        let next_carry = *plane & carry;
+        *plane ^= carry;
+        carry = next_carry;
+    }
+}
+
+/// The bits whose counter equals `count`.
+fn counts_equal(counts: &[u64; 4], count: usize) -> u64 {
+    counts
+        .iter()
+        .enumerate()
+        .fold(!0, |matches, (bit, &plane)| {
+            matches & if count >> bit & 1 == 1 { plane } else { !plane }
+        })
+}
+
+/// Packs the cells and rule; the boundary mode and neighborhood are not carried over.
+impl From<&GameOfLife> for PackedLife {
+    fn from(game: &GameOfLife) -> PackedLife {
+        let (rows, cols) = game.dimensions();
+        let words_per_row = cols.div_ceil(WORD_BITS);
+        let mut words = vec![0; rows * words_per_row];
+        for (row_num, row) in game.cells().iter().enumerate() {
+            for (col_num, _) in row.iter().enumerate().filter(|(_, &alive)| alive) {
+                words[row_num * words_per_row + col_num / WORD_BITS] |= 1 << (col_num % WORD_BITS);
+            }
+        }
+        PackedLife {
+            rows,
+            cols,
+            words_per_row,
+            words,
+            rule: game.rule(),
+        }
+    }
+}
+
+impl From<&PackedLife> for GameOfLife {
+    fn from(packed: &PackedLife) -> GameOfLife {
+        GameOfLife::from_cells(
+            (0..packed.rows)
+                .map(|row| {
+                    (0..packed.cols)
+                        .map(|col| packed.get(row, col) == Some(true))
+                        .collect()
+                })
+                .collect(),
+        )
+        .with_rule(packed.rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+    use crate::SplitMix64;
+
+    #[test]
+    fn test_matches_dense_engine() {
+        let mut rng = SplitMix64::new(29);
+        for &(rows, cols, rule) in &[
+            (1, 1, "B3/S23"),
+            (40, 64, "B3/S23"),
+            (37, 130, "B3/S23"),
+            (50, 70, "B36/S23"),
+            (20, 90, "B2/S"),
+        ] {
+            let mut game =
+                GameOfLife::random(rows, cols, 0.4, &mut rng).with_rule(Rule::parse(rule).unwrap());
+            let mut packed = PackedLife::from(&game);
+            for _ in 0..10 {
+                assert_eq!(GameOfLife::from(&packed), game);
+                assert_eq!(packed.population(), game.population());
+                game = game.next_state();
+                packed = packed.next_state();
+            }
+        }
+    }
+
+    #[test]
+    fn test_uses_an_eighth_of_the_memory() {
+        let game = GameOfLife::random(1000, 1000, 0.5, &mut SplitMix64::new(1));
+        let dense_bytes: usize = game
+            .cells()
+            .iter()
+            .map(|row| row.len() * mem::size_of::<bool>())
+            .sum();
+        let packed = PackedLife::from(&game);
+        let packed_bytes = packed.words.len() * mem::size_of::<u64>();
+        // each row of 1000 cells rounds up to 16 words, 1024 bits
+        assert_eq!(packed_bytes, 1000 * 16 * 8);
+        assert!(packed_bytes * 8 < dense_bytes * 11 / 10);
+    }
+}
@@ -0,0 +1,114 @@
+use std::mem;
+use std::num::NonZeroUsize;
+use std::thread;
+
+use crate::summed_area::SummedArea;
+use crate::GameOfLife;
+
+impl GameOfLife {
+    /// Computes the following generation like [`GameOfLife::next_state`], splitting the rows
+    /// between a thread for each available core.
+    ///
+    /// Each row of the next generation depends only on this one, so the result is identical to
+    /// `next_state`. Spawning threads has a cost, so this only pays off on large boards.
+    pub fn next_state_par(&self) -> GameOfLife {
+        let growth = self.growth();
+        if growth > 0 {
+            return self.padded(growth).next_state_par();
+        }
+        let mut cells = Vec::new();
+        self.write_next_cells_par(&mut SummedArea::default(), &mut cells);
+        self.with_cells(cells)
+    }
+
+    /// Advances this board to the following generation in place like
+    /// [`GameOfLife::step_mut`], splitting the rows between threads as
+    /// [`next_state_par`](GameOfLife::next_state_par) does.
+    ///
+    /// The buffers that `step_mut` keeps are reused, so after the first call only spawning the
+    /// threads allocates.
+    pub fn step_mut_par(&mut self) {
+        self.grow();
+        let mut scratch = mem::take(&mut self.scratch);
+        self.write_next_cells_par(&mut scratch.sums, &mut scratch.cells);
+        mem::swap(&mut self.cells, &mut scratch.cells);
+        self.scratch = scratch;
+    }
+
+    /// Overwrites `next` with the following generation, using `sums` to count neighbors and a
+    /// thread for each available core to compute the rows.
+    fn write_next_cells_par(&self, sums: &mut SummedArea, next: &mut Vec<Vec<bool>>) {
+        sums.build(self);
+        next.resize_with(self.cells.len(), Vec::new);
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let rows_per_thread = next.len().div_ceil(threads).max(1);
+        let rule = |alive, count| self.rule.is_alive_next(alive, count);
+        let sums = &*sums;
+        thread::scope(|scope| {
+            for (chunk_num, chunk) in next.chunks_mut(rows_per_thread).enumerate() {
+                let rule = &rule;
+                scope.spawn(move || {
+                    self.write_next_rows(sums, chunk_num * rows_per_thread, chunk, rule)
+                });
+            }
+        });
+    }
+}
+
+/// Advances each of `boards` by `generations` generations, splitting the boards between a thread
+/// for each available core, and returns them in the same order.
+///
+/// The boards are independent, so unlike [`GameOfLife::next_state_par`] the threads never wait
+/// on each other, and many small boards parallelize well.
+pub fn evolve_many(mut boards: Vec<GameOfLife>, generations: usize) -> Vec<GameOfLife> {
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let boards_per_thread = boards.len().div_ceil(threads).max(1);
+    thread::scope(|scope| {
+        for chunk in boards.chunks_mut(boards_per_thread) {
+            scope.spawn(move || {
+                for board in chunk {
+                    for _ in 0..generations {
+                        board.step_mut();
+                    }
+                }
+            });
+        }
+    });
+    boards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::evolve_many;
+    use crate::{BoundaryMode, GameOfLife, SplitMix64};
+
+    #[test]
+    fn test_matches_serial() {
+        let mut rng = SplitMix64::new(30);
+        for &(rows, cols) in &[(0, 0), (1, 9), (3, 3), (301, 199)] {
+            let mut game =
+                GameOfLife::random(rows, cols, 0.3, &mut rng).with_boundary(BoundaryMode::Toroidal);
+            let mut stepped = game.clone();
+            for _ in 0..5 {
+                let parallel = game.next_state_par();
+                game = game.next_state();
+                assert_eq!(parallel, game);
+                stepped.step_mut_par();
+                assert_eq!(stepped, game);
+            }
+        }
+    }
+
+    #[test]
+    fn test_evolve_many_keeps_order() {
+        let mut rng = SplitMix64::new(100);
+        let boards: Vec<GameOfLife> = (0..37)
+            .map(|size| GameOfLife::random(size % 9, size % 7 + 1, 0.4, &mut rng))
+            .collect();
+        let expected: Vec<GameOfLife> =
+            boards.iter().map(|board| board.nth_generation(6)).collect();
+        assert_eq!(evolve_many(boards.clone(), 6), expected);
+        assert_eq!(evolve_many(boards.clone(), 0), boards);
+        assert!(evolve_many(Vec::new(), 3).is_empty());
+    }
+}
@@ -0,0 +1,487 @@
+//! Well-known patterns, each on a board just large enough to hold it.
+//!
+//! Patterns that grow or move need room, so stamp them onto a larger board with
+//! [`GameOfLife::overlay`] or add space around them with [`GameOfLife::padded`].
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::GameOfLife;
+
+fn pattern(lines: &[&str]) -> GameOfLife {
+    GameOfLife::from_lines_with(lines, 'O')
+}
+
+/// The glider, the smallest spaceship, heading down and to the right.
+pub fn glider() -> GameOfLife {
+    #[rustfmt::skip]
+    let glider = pattern(&[
+        ".O.",
+        "..O",
+        "OOO",
+    ]);
+    glider
+}
+
+/// The blinker, a period 2 oscillator, in its vertical phase.
+pub fn blinker() -> GameOfLife {
+    #[rustfmt::skip]
+    let blinker = pattern(&[
+        "O",
+        "O",
+        "O",
+    ]);
+    blinker
+}
+
+/// The block, the most common still life.
+pub fn block() -> GameOfLife {
+    #[rustfmt::skip]
+    let block = pattern(&[
+        "OO",
+        "OO",
+    ]);
+    block
+}
+
+/// The beacon, a period 2 oscillator, in the phase where all six cells are alive.
+pub fn beacon() -> GameOfLife {
+    #[rustfmt::skip]
+    let beacon = pattern(&[
+        "OO..",
+        "OO..",
+        "..OO",
+        "..OO",
+    ]);
+    beacon
+}
+
+/// The beehive, a six cell still life.
+pub fn beehive() -> GameOfLife {
+    #[rustfmt::skip]
+    let beehive = pattern(&[
+        ".OO.",
+        "O..O",
+        ".OO.",
+    ]);
+    beehive
+}
+
+/// The tub, a four cell still life.
+pub fn tub() -> GameOfLife {
+    #[rustfmt::skip]
+    let tub = pattern(&[
+        ".O.",
+        "O.O",
+        ".O.",
+    ]);
+    tub
+}
+
+/// The boat, a five cell still life.
+pub fn boat() -> GameOfLife {
+    #[rustfmt::skip]
+    let boat = pattern(&[
+        "OO.",
+        "O.O",
+        ".O.",
+    ]);
+    boat
+}
+
+/// The ship, a six cell still life.
+pub fn ship() -> GameOfLife {
+    #[rustfmt::skip]
+    let ship = pattern(&[
+        "OO.",
+        "O.O",
+        ".OO",
+    ]);
+    ship
+}
+
+/// The loaf, a seven cell still life.
+pub fn loaf() -> GameOfLife {
+    #[rustfmt::skip]
+    let loaf = pattern(&[
+        ".OO.",
+        "O..O",
+        ".O.O",
+        "..O.",
+    ]);
+    loaf
+}
+
+/// The toad, a period 2 oscillator, in its flat phase.
+pub fn toad() -> GameOfLife {
+    #[rustfmt::skip]
+    let toad = pattern(&[
+        ".OOO",
+        "OOO.",
+    ]);
+    toad
+}
+
+/// The lightweight spaceship, heading left at half the speed of light.
+pub fn lwss() -> GameOfLife {
+    #[rustfmt::skip]
+    let lwss = pattern(&[
+        ".O..O",
+        "O....",
+        "O...O",
+        "OOOO.",
+    ]);
+    lwss
+}
+
+/// The middleweight spaceship, heading left at half the speed of light.
+pub fn mwss() -> GameOfLife {
+    #[rustfmt::skip]
+    let mwss = pattern(&[
+        "...O..",
+        ".O...O",
+        "O.....",
+        "O....O",
+        "OOOOO.",
+    ]);
+    mwss
+}
+
+/// The heavyweight spaceship, heading left at half the speed of light.
+pub fn hwss() -> GameOfLife {
+    #[rustfmt::skip]
+    let hwss = pattern(&[
+        "...OO..",
+        ".O....O",
+        "O......",
+        "O.....O",
+        "OOOOOO.",
+    ]);
+    hwss
+}
+
+/// The pulsar, a period 3 oscillator.
+pub fn pulsar() -> GameOfLife {
+    #[rustfmt::skip]
+    let pulsar = pattern(&[
+        "..OOO...OOO..",
+        ".............",
+        "O....O.O....O",
+        "O....O.O....O",
+        "O....O.O....O",
+        "..OOO...OOO..",
+        ".............",
+        "..OOO...OOO..",
+        "O....O.O....O",
+        "O....O.O....O",
+        "O....O.O....O",
+        ".............",
+        "..OOO...OOO..",
+    ]);
+    pulsar
+}
+
+/// The pentadecathlon, a period 15 oscillator, which needs room to grow into in some phases.
+pub fn pentadecathlon() -> GameOfLife {
+    #[rustfmt::skip]
+    let pentadecathlon = pattern(&[
+        "..O....O..",
+        "OO.OOOO.OO",
+        "..O....O..",
+    ]);
+    pentadecathlon
+}
+
+/// The R-pentomino, a methuselah that takes 1103 generations to settle, firing off gliders.
+pub fn r_pentomino() -> GameOfLife {
+    #[rustfmt::skip]
+    let r_pentomino = pattern(&[
+        ".OO",
+        "OO.",
+        ".O.",
+    ]);
+    r_pentomino
+}
+
+/// The acorn, a methuselah that grows from 7 cells to 633 over 5206 generations.
+pub fn acorn() -> GameOfLife {
+    #[rustfmt::skip]
+    let acorn = pattern(&[
+        ".O.....",
+        "...O...",
+        "OO..OOO",
+    ]);
+    acorn
+}
+
+/// Bill Gosper's glider gun, which emits a new glider every 30 generations.
+pub fn gosper_glider_gun() -> GameOfLife {
+    #[rustfmt::skip]
+    let gun = pattern(&[
+        "........................O...........",
+        "......................O.O...........",
+        "............OO......OO............OO",
+        "...........O...O....OO............OO",
+        "OO........O.....O...OO..............",
+        "OO........O...O.OO....O.O...........",
+        "..........O.....O.......O...........",
+        "...........O...O....................",
+        "............OO......................",
+    ]);
+    gun
+}
+
+/// A pattern that [`GameOfLife::classify`] recognizes.
+struct Named {
+    name: &'static str,
+    pattern: fn() -> GameOfLife,
+    period: usize,
+}
+
+const NAMED: [Named; 15] = [
+    Named {
+        name: "block",
+        pattern: block,
+        period: 1,
+    },
+    Named {
+        name: "beehive",
+        pattern: beehive,
+        period: 1,
+    },
+    Named {
+        name: "tub",
+        pattern: tub,
+        period: 1,
+    },
+    Named {
+        name: "boat",
+        pattern: boat,
+        period: 1,
+    },
+    Named {
+        name: "ship",
+        pattern: ship,
+        period: 1,
+    },
+    Named {
+        name: "loaf",
+        pattern: loaf,
+        period: 1,
+    },
+    Named {
+        name: "blinker",
+        pattern: blinker,
+        period: 2,
+    },
+    Named {
+        name: "toad",
+        pattern: toad,
+        period: 2,
+    },
+    Named {
+        name: "beacon",
+        pattern: beacon,
+        period: 2,
+    },
+    Named {
+        name: "pulsar",
+        pattern: pulsar,
+        period: 3,
+    },
+    Named {
+        name: "pentadecathlon",
+        pattern: pentadecathlon,
+        period: 15,
+    },
+    Named {
+        name: "glider",
+        pattern: glider,
+        period: 4,
+    },
+    Named {
+        name: "lwss",
+        pattern: lwss,
+        period: 4,
+    },
+    Named {
+        name: "mwss",
+        pattern: mwss,
+        period: 4,
+    },
+    Named {
+        name: "hwss",
+        pattern: hwss,
+        period: 4,
+    },
+];
+
+impl GameOfLife {
+    /// The name of the well-known pattern on this board, if it holds exactly one, in any phase,
+    /// position, rotation or reflection.
+    ///
+    /// The block, beehive, tub, boat, ship, loaf, blinker, toad, beacon, pulsar,
+    /// pentadecathlon, glider and the three spaceships are recognized.
+    pub fn classify(&self) -> Option<&'static str> {
+        let canonical = self.canonical();
+        catalog()
+            .iter()
+            .find(|(phase, _)| *phase == canonical.cells)
+            .map(|&(_, name)| name)
+    }
+}
+
+/// The cells of the canonical form of one phase of a pattern [`GameOfLife::classify`]
+/// recognizes, with the pattern's name.
+type Entry = (Vec<Vec<bool>>, &'static str);
+
+/// An entry for every phase of every recognized pattern.
+type Catalog = [Entry];
+
+/// The catalog of recognized patterns, built once and then shared.
+#[cfg(feature = "std")]
+pub(crate) fn catalog() -> Cow<'static, Catalog> {
+    static CATALOG: OnceLock<Vec<Entry>> = OnceLock::new();
+    Cow::Borrowed(CATALOG.get_or_init(build_catalog))
+}
+
+/// The catalog of recognized patterns, built afresh since there's nowhere to keep it.
+#[cfg(not(feature = "std"))]
+pub(crate) fn catalog() -> Cow<'static, Catalog> {
+    Cow::Owned(build_catalog())
+}
+
+fn build_catalog() -> Vec<Entry> {
+    NAMED
+        .iter()
+        .flat_map(|named| {
+            // as much room as any phase needs to grow into
+            let phases = (named.pattern)().padded(3).generations().take(named.period);
+            phases.map(move |phase| (phase.canonical().cells, named.name))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dimensions() {
+        assert_eq!(glider().dimensions(), (3, 3));
+        assert_eq!(blinker().dimensions(), (3, 1));
+        assert_eq!(block().dimensions(), (2, 2));
+        assert_eq!(beacon().dimensions(), (4, 4));
+        assert_eq!(beehive().dimensions(), (3, 4));
+        assert_eq!(tub().dimensions(), (3, 3));
+        assert_eq!(gosper_glider_gun().dimensions(), (9, 36));
+        assert_eq!(gosper_glider_gun().population(), 36);
+        assert_eq!(pulsar().dimensions(), (13, 13));
+        assert_eq!(pulsar().population(), 48);
+        assert_eq!(acorn().population(), 7);
+    }
+
+    #[test]
+    fn test_spaceships_move_left() {
+        for ship in &[lwss(), mwss(), hwss()] {
+            let board = ship.padded(3);
+            let mut moved = board.clone();
+            moved.shift(0, -2);
+            assert_eq!(board.nth_generation(4), moved);
+        }
+    }
+
+    #[test]
+    fn test_long_period_oscillators() {
+        assert_eq!(pulsar().padded(1).period(3), Some(3));
+        assert_eq!(pentadecathlon().padded(3).period(15), Some(15));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_methuselahs_settle() {
+        use core::convert::TryFrom;
+
+        let settled = |pattern: GameOfLife, generations| {
+            let mut game = crate::HashLife::try_from(&pattern).unwrap();
+            game.advance(generations);
+            game.population()
+        };
+        assert_eq!(settled(r_pentomino(), 1 << 11), 116);
+        assert_eq!(settled(acorn(), 1 << 13), 633);
+    }
+
+    #[test]
+    fn test_oscillators_and_still_lifes() {
+        assert!(block().is_still_life());
+        assert!(beehive().is_still_life());
+        assert!(tub().is_still_life());
+        for still_life in &[boat(), ship(), loaf()] {
+            assert!(still_life.padded(1).is_still_life());
+        }
+        assert_eq!(blinker().padded(1).period(4), Some(2));
+        assert_eq!(toad().padded(1).period(4), Some(2));
+        assert_eq!(beacon().padded(1).period(4), Some(2));
+    }
+
+    #[test]
+    fn test_glider_glides() {
+        let mut board = GameOfLife::from_lines(&["          "; 10]);
+        board.overlay(&glider(), 0, 0);
+        let mut moved = GameOfLife::from_lines(&["          "; 10]);
+        moved.overlay(&glider(), 1, 1);
+        assert_eq!(board.nth_generation(4), moved);
+    }
+
+    #[test]
+    fn test_classify_recognizes_every_phase_and_orientation() {
+        assert_eq!(block().classify(), Some("block"));
+        assert_eq!(beehive().rotate_cw().classify(), Some("beehive"));
+        assert_eq!(tub().padded(3).classify(), Some("tub"));
+        assert_eq!(blinker().padded(1).next_state().classify(), Some("blinker"));
+        assert_eq!(beacon().padded(1).next_state().classify(), Some("beacon"));
+        let glider = glider().padded(4);
+        for phase in glider.generations().take(4) {
+            assert_eq!(phase.flip_vertical().classify(), Some("glider"));
+        }
+        assert_eq!(pulsar().padded(1).next_state().classify(), Some("pulsar"));
+        let pentadecathlon = pentadecathlon().padded(3).nth_generation(7);
+        assert_eq!(
+            pentadecathlon.transpose().classify(),
+            Some("pentadecathlon")
+        );
+        assert_eq!(
+            hwss().padded(2).next_state().rotate_cw().classify(),
+            Some("hwss")
+        );
+        assert_eq!(lwss().flip_horizontal().classify(), Some("lwss"));
+        assert_eq!(loaf().rotate_cw().classify(), Some("loaf"));
+        assert_eq!(toad().padded(1).next_state().classify(), Some("toad"));
+        assert_eq!(boat().classify(), Some("boat"));
+        assert_eq!(ship().transpose().classify(), Some("ship"));
+        assert_eq!(r_pentomino().classify(), None);
+    }
+
+    #[test]
+    fn test_classify_ignores_board_settings() {
+        let glider = glider().with_boundary(crate::BoundaryMode::Toroidal);
+        assert_eq!(glider.classify(), Some("glider"));
+        let block = block()
+            .with_rule(crate::Rule::parse("B36/S23").unwrap())
+            .with_radius(2)
+            .with_auto_grow(true);
+        assert_eq!(block.classify(), Some("block"));
+    }
+
+    #[test]
+    fn test_classify_rejects_other_boards() {
+        assert_eq!(GameOfLife::from_lines(&["   "; 3]).classify(), None);
+        assert_eq!(gosper_glider_gun().classify(), None);
+        let mut two_blocks = GameOfLife::from_lines(&["       "; 2]);
+        two_blocks.overlay(&block(), 0, 0);
+        two_blocks.overlay(&block(), 0, 5);
+        assert_eq!(two_blocks.classify(), None);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::GameOfLife;
+
+/// A source of random numbers for generating boards.
+///
+/// Implement this for any generator to use it with [`GameOfLife::random`]; [`SplitMix64`] is a
+/// small seedable implementation for reproducible results.
+pub trait Rng {
+    /// Returns the next uniformly distributed 64-bit value.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a uniformly distributed value in `[0.0, 1.0)`.
+    fn next_f64(&mut self) -> f64 {
+        // the top 53 bits fill an f64's mantissa exactly
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// The SplitMix64 generator: fast, seedable and deterministic, but not cryptographically secure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a generator whose output is determined entirely by `seed`.
+    pub fn new(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl GameOfLife {
+    /// Creates a `rows` by `cols` board where each cell is alive with probability `density`.
+    ///
+    /// `density` is clamped to `[0.0, 1.0]`, so 0.0 gives an all-dead board and 1.0 an all-alive one.
+    pub fn random(rows: usize, cols: usize, density: f64, rng: &mut impl Rng) -> GameOfLife {
+        let density = density.clamp(0.0, 1.0);
+        GameOfLife::from_cells(
+            (0..rows)
+                .map(|_| (0..cols).map(|_| rng.next_f64() < density).collect())
+                .collect(),
+        )
+    }
+
+    /// Creates a board as [`GameOfLife::random`] does, from a [`SplitMix64`] seeded with a hash
+    /// of `seed`, so that a word or phrase can stand in for a numeric seed.
+    ///
+    /// The hash doesn't change between runs, versions or platforms, so the same seed and
+    /// parameters always give the same board.
+    pub fn random_from_seed(rows: usize, cols: usize, density: f64, seed: &str) -> GameOfLife {
+        GameOfLife::random(rows, cols, density, &mut SplitMix64::new(hash_seed(seed)))
+    }
+}
+
+/// 64 bit FNV-1a over the UTF-8 bytes of `seed`.
+fn hash_seed(seed: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    seed.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_is_reproducible() {
+        let game = GameOfLife::random(20, 30, 0.5, &mut SplitMix64::new(42));
+        assert_eq!(game.dimensions(), (20, 30));
+        assert_eq!(
+            game,
+            GameOfLife::random(20, 30, 0.5, &mut SplitMix64::new(42))
+        );
+        assert_ne!(
+            game,
+            GameOfLife::random(20, 30, 0.5, &mut SplitMix64::new(43))
+        );
+    }
+
+    #[test]
+    fn test_random_density_extremes() {
+        let mut rng = SplitMix64::new(7);
+        assert_eq!(GameOfLife::random(10, 10, 0.0, &mut rng).population(), 0);
+        assert_eq!(GameOfLife::random(10, 10, 1.0, &mut rng).population(), 100);
+        assert_eq!(GameOfLife::random(10, 10, 2.5, &mut rng).population(), 100);
+        assert_eq!(GameOfLife::random(10, 10, -1.0, &mut rng).population(), 0);
+    }
+
+    #[test]
+    fn test_random_from_seed_is_reproducible() {
+        let game = GameOfLife::random_from_seed(20, 30, 0.5, "acorn");
+        assert_eq!(game, GameOfLife::random_from_seed(20, 30, 0.5, "acorn"));
+        assert_ne!(game, GameOfLife::random_from_seed(20, 30, 0.5, "Acorn"));
+        assert_ne!(game, GameOfLife::random_from_seed(20, 30, 0.5, ""));
+        // the FNV-1a test vector
+        assert_eq!(hash_seed("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
@@ -0,0 +1,239 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::iter;
+
+use crate::GameOfLife;
+
+/// How [`GameOfLife::render`] draws a board.
+///
+/// The default draws live cells as `•` and dead cells as spaces with no border, just as
+/// [`Display`](core::fmt::Display) does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RenderOptions {
+    /// The character for a live cell.
+    pub alive: char,
+    /// The character for a dead cell.
+    pub dead: char,
+    /// Whether to frame the board with `+`, `-` and `|`, which shows where its edges are when
+    /// the cells along them are dead.
+    pub border: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> RenderOptions {
+        RenderOptions {
+            alive: '•',
+            dead: ' ',
+            border: false,
+        }
+    }
+}
+
+/// How [`GameOfLife::to_svg`] draws a board.
+///
+/// Colors are any SVG paint, such as `black`, `#1e90ff` or `rgb(0, 128, 0)`. The default draws
+/// black 10 pixel cells on a transparent background with no grid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SvgOptions {
+    /// The width and height of each cell, in pixels.
+    pub cell_size: u32,
+    /// The fill color of live cells.
+    pub alive: String,
+    /// The background color, or `None` to leave dead cells transparent.
+    pub background: Option<String>,
+    /// The color of lines drawn between cells, or `None` for no grid.
+    pub grid: Option<String>,
+}
+
+impl Default for SvgOptions {
+    fn default() -> SvgOptions {
+        SvgOptions {
+            cell_size: 10,
+            alive: "black".to_string(),
+            background: None,
+            grid: None,
+        }
+    }
+}
+
+impl GameOfLife {
+    /// Renders this board as text, one line per row, as `options` describes.
+    ///
+    /// There is no newline after the last line.
+    pub fn render(&self, options: &RenderOptions) -> String {
+        let mut lines: Vec<String> = self
+            .cells()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&alive| if alive { options.alive } else { options.dead })
+                    .collect()
+            })
+            .collect();
+        if options.border {
+            let (_, cols) = self.dimensions();
+            let edge: String = iter::once('+')
+                .chain(iter::repeat_n('-', cols))
+                .chain(iter::once('+'))
+                .collect();
+            for line in &mut lines {
+                line.insert(0, '|');
+                line.push('|');
+            }
+            lines.insert(0, edge.clone());
+            lines.push(edge);
+        }
+        lines.join("\n")
+    }
+
+    /// Renders this board as an SVG image, with one `rect` per live cell, as `options` describes.
+    ///
+    /// The `viewBox` is cropped to the live cells' bounding box, so the image frames the pattern
+    /// rather than the whole board, while the `rect`s keep their positions on the board. A board
+    /// with no live cells is an empty image.
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        let size = options.cell_size as usize;
+        let (top, left, rows, cols) = match self.bounding_box() {
+            Some((min_row, min_col, max_row, max_col)) => (
+                min_row,
+                min_col,
+                max_row - min_row + 1,
+                max_col - min_col + 1,
+            ),
+            None => (0, 0, 0, 0),
+        };
+        let (x, y, width, height) = (left * size, top * size, cols * size, rows * size);
+
+        // writing to a String never fails
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+            width, height, x, y, width, height
+        );
+        if let Some(background) = &options.background {
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                x, y, width, height, background
+            );
+        }
+        let _ = writeln!(svg, r#"<g fill="{}">"#, options.alive);
+        for (row, col) in self.live_cells() {
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}"/>"#,
+                col * size,
+                row * size,
+                size,
+                size
+            );
+        }
+        svg.push_str("</g>\n");
+        if let Some(grid) = &options.grid {
+            let _ = writeln!(svg, r#"<g stroke="{}" stroke-width="1">"#, grid);
+            for line in 0..=rows {
+                let line_y = y + line * size;
+                let _ = writeln!(
+                    svg,
+                    r#"<line x1="{}" y1="{}" x2="{}" y2="{}"/>"#,
+                    x,
+                    line_y,
+                    x + width,
+                    line_y
+                );
+            }
+            for line in 0..=cols {
+                let line_x = x + line * size;
+                let _ = writeln!(
+                    svg,
+                    r#"<line x1="{}" y1="{}" x2="{}" y2="{}"/>"#,
+                    line_x,
+                    y,
+                    line_x,
+                    y + height
+                );
+            }
+            svg.push_str("</g>\n");
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let game = GameOfLife::from_lines(&["• ", " •", "  "]);
+        assert_eq!(game.render(&RenderOptions::default()), game.to_string());
+        let options = RenderOptions {
+            alive: '█',
+            dead: '.',
+            border: true,
+        };
+        #[rustfmt::skip]
+        assert_eq!(game.render(&options), [
+            "+--+",
+            "|█.|",
+            "|.█|",
+            "|..|",
+            "+--+",
+        ].join("\n"));
+        assert_eq!(GameOfLife::from_lines(&[]).render(&options), "++\n++");
+        assert_eq!(
+            GameOfLife::from_lines(&[]).render(&RenderOptions::default()),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_to_svg() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            "    ",
+            "  • ",
+            " •• ",
+            "    ",
+        ]);
+        let options = SvgOptions {
+            cell_size: 2,
+            alive: "#123456".to_string(),
+            background: Some("white".to_string()),
+            grid: None,
+        };
+        assert_eq!(
+            game.to_svg(&options),
+            [
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="4" viewBox="2 2 4 4">"#,
+                r#"<rect x="2" y="2" width="4" height="4" fill="white"/>"#,
+                r##"<g fill="#123456">"##,
+                r#"<rect x="4" y="2" width="2" height="2"/>"#,
+                r#"<rect x="2" y="4" width="2" height="2"/>"#,
+                r#"<rect x="4" y="4" width="2" height="2"/>"#,
+                "</g>",
+                "</svg>",
+                "",
+            ]
+            .join("\n")
+        );
+
+        // a 2x2 box of cells has 3 lines each way
+        let grid = SvgOptions {
+            grid: Some("gray".to_string()),
+            ..SvgOptions::default()
+        };
+        let svg = game.to_svg(&grid);
+        assert_eq!(svg.matches("<line").count(), 6);
+        assert!(svg.contains(r#"<line x1="10" y1="30" x2="30" y2="30"/>"#));
+
+        let empty = GameOfLife::dead(3, 3).to_svg(&SvgOptions::default());
+        assert!(empty.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="0" viewBox="0 0 0 0">"#
+        ));
+        assert!(!empty.contains("<rect"));
+    }
+}
@@ -0,0 +1,287 @@
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+/// A life-like rule in B/S notation, such as Conway's `B3/S23`.
+///
+/// The birth and survival neighbor counts are stored as bit sets, where bit `n` is set when `n`
+/// living neighbors cause a dead cell to be born, or a living cell to survive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rule {
+    birth: u16,
+    survival: u16,
+}
+
+impl Rule {
+    /// Conway's Game of Life, `B3/S23`.
+    pub fn conway() -> Rule {
+        /*
+         * https://en.wikipedia.org/wiki/Conway%27s_Game_of_Life#Rules
+         * Any live cell with two or three live neighbors survives.
+         * Any dead cell with three live neighbors becomes a live cell.
+         * All other live cells die in the next generation. Similarly, all other dead cells stay dead.
+         */
+        Rule {
+            birth: 1 << 3,
+            survival: 1 << 2 | 1 << 3,
+        }
+    }
+
+    /// Parses a rulestring such as `B36/S23` or `B2/S`.
+    ///
+    /// The `B` and `S` prefixes may be in either case, and each may be followed by any of the
+    /// neighbor counts 0 through 8.
+    pub fn parse(rulestring: &str) -> Result<Rule, RuleError> {
+        let mut parts = rulestring.trim().splitn(2, '/');
+        let birth = parts.next().ok_or(RuleError::Malformed)?;
+        let survival = parts.next().ok_or(RuleError::Malformed)?;
+        Ok(Rule {
+            birth: parse_counts(birth, 'B')?,
+            survival: parse_counts(survival, 'S')?,
+        })
+    }
+
+    /// Decides whether a cell is alive in the next generation.
+    pub fn is_alive_next(&self, currently_alive: bool, living_neighbor_count: usize) -> bool {
+        let counts = if currently_alive {
+            self.survival
+        } else {
+            self.birth
+        };
+        living_neighbor_count <= MAX_COUNT as usize && counts & 1 << living_neighbor_count != 0
+    }
+
+    /// The birth and survival counts as bit sets.
+    pub(crate) fn to_bits(self) -> (u16, u16) {
+        (self.birth, self.survival)
+    }
+
+    /// The rule with the given birth and survival bit sets, or `None` if either holds a count
+    /// greater than 8.
+    pub(crate) fn from_bits(birth: u16, survival: u16) -> Option<Rule> {
+        let valid = |counts: u16| counts >> (MAX_COUNT + 1) == 0;
+        if valid(birth) && valid(survival) {
+            Some(Rule { birth, survival })
+        } else {
+            None
+        }
+    }
+}
+
+const MAX_COUNT: u32 = 8;
+
+/// A rule spelled out as a table of the outcome for each neighbor count from 0 to 8, for dead
+/// and for living cells.
+///
+/// Every table is a valid rule, so a table converts to and from a [`Rule`] without loss; boards
+/// store the compact `Rule` form. The default table is Conway's `B3/S23`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleTable {
+    /// `born[n]` is whether a dead cell with `n` living neighbors comes to life.
+    pub born: [bool; 9],
+    /// `survive[n]` is whether a living cell with `n` living neighbors stays alive.
+    pub survive: [bool; 9],
+}
+
+impl Default for RuleTable {
+    fn default() -> RuleTable {
+        Rule::conway().into()
+    }
+}
+
+impl From<Rule> for RuleTable {
+    fn from(rule: Rule) -> RuleTable {
+        let table = |counts: u16| {
+            let mut outcomes = [false; 9];
+            for (count, outcome) in outcomes.iter_mut().enumerate() {
+                *outcome = counts & 1 << count != 0;
+            }
+            outcomes
+        };
+        RuleTable {
+            born: table(rule.birth),
+            survive: table(rule.survival),
+        }
+    }
+}
+
+impl From<RuleTable> for Rule {
+    fn from(table: RuleTable) -> Rule {
+        let counts = |outcomes: [bool; 9]| {
+            (0..)
+                .zip(outcomes)
+                .filter(|&(_, outcome)| outcome)
+                .fold(0, |counts, (count, _)| counts | 1 << count)
+        };
+        Rule {
+            birth: counts(table.born),
+            survival: counts(table.survive),
+        }
+    }
+}
+
+fn parse_counts(part: &str, prefix: char) -> Result<u16, RuleError> {
+    let mut chars = part.chars();
+    match chars.next() {
+        Some(first) if first.eq_ignore_ascii_case(&prefix) => {}
+        _ => return Err(RuleError::Malformed),
+    }
+    chars.try_fold(0, |counts, char| match char.to_digit(10) {
+        Some(count) if count <= MAX_COUNT => Ok(counts | 1 << count),
+        Some(count) => Err(RuleError::CountOutOfRange(count)),
+        None => Err(RuleError::Malformed),
+    })
+}
+
+impl Default for Rule {
+    fn default() -> Rule {
+        Rule::conway()
+    }
+}
+
+/// Parses a rulestring as [`Rule::parse`] does.
+impl FromStr for Rule {
+    type Err = RuleError;
+
+    fn from_str(rulestring: &str) -> Result<Rule, RuleError> {
+        Rule::parse(rulestring)
+    }
+}
+
+/// Renders the rule in `B<counts>/S<counts>` notation.
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let write_counts = |f: &mut fmt::Formatter, counts: u16| {
+            (0..=MAX_COUNT)
+                .filter(|count| counts & 1 << count != 0)
+                .try_for_each(|count| write!(f, "{}", count))
+        };
+        f.write_str("B")?;
+        write_counts(f, self.birth)?;
+        f.write_str("/S")?;
+        write_counts(f, self.survival)
+    }
+}
+
+/// Returned when a rulestring cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleError {
+    /// The rulestring was not of the form `B<counts>/S<counts>`.
+    Malformed,
+    /// A neighbor count greater than 8 appeared in the rulestring.
+    CountOutOfRange(u32),
+    /// A Generations rulestring asked for fewer than 2 or more than 255 cell states.
+    StatesOutOfRange(u32),
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleError::Malformed => f.write_str("rulestring must look like B3/S23"),
+            RuleError::CountOutOfRange(count) => {
+                write!(f, "neighbor count {} is greater than 8", count)
+            }
+            RuleError::StatesOutOfRange(states) => {
+                write!(f, "{} states is not between 2 and 255", states)
+            }
+        }
+    }
+}
+
+impl Error for RuleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameOfLife;
+
+    #[test]
+    fn test_parse_round_trips() {
+        assert_eq!(Rule::parse("B3/S23"), Ok(Rule::conway()));
+        assert_eq!(
+            Rule::parse("b3/s32").map(|rule| rule.to_string()),
+            Ok("B3/S23".to_string())
+        );
+        assert_eq!(
+            Rule::parse("B2/S").map(|rule| rule.to_string()),
+            Ok("B2/S".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_rulestrings() {
+        assert_eq!(Rule::parse(""), Err(RuleError::Malformed));
+        assert_eq!(Rule::parse("B3S23"), Err(RuleError::Malformed));
+        assert_eq!(Rule::parse("S23/B3"), Err(RuleError::Malformed));
+        assert_eq!(Rule::parse("B3/S2x"), Err(RuleError::Malformed));
+        assert_eq!(Rule::parse("B39/S23"), Err(RuleError::CountOutOfRange(9)));
+    }
+
+    #[test]
+    fn test_rule_table_round_trips() {
+        let conway = RuleTable::default();
+        assert_eq!(Rule::from(conway), Rule::conway());
+        assert!(conway.born[3] && !conway.born[2]);
+        assert!(conway.survive[2] && conway.survive[3] && !conway.survive[4]);
+
+        let rule = Rule::parse("B0368/S1").unwrap();
+        let table = RuleTable::from(rule);
+        assert_eq!(
+            table.born,
+            [true, false, false, true, false, false, true, false, true]
+        );
+        assert_eq!(Rule::from(table), rule);
+
+        // a table can describe any rule, and boards follow it exactly
+        let mut exotic = RuleTable {
+            born: [false; 9],
+            survive: [true; 9],
+        };
+        exotic.born[1] = true;
+        let rule = Rule::from(exotic);
+        assert_eq!(rule.to_string(), "B1/S012345678");
+        for count in 0..=8 {
+            assert_eq!(rule.is_alive_next(false, count), exotic.born[count]);
+            assert_eq!(rule.is_alive_next(true, count), exotic.survive[count]);
+        }
+    }
+
+    #[test]
+    fn test_highlife_births_on_six_neighbors() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            "•••",
+            "• •",
+            "•  ",
+        ]);
+        assert!(!game.next_state().cells()[1][1]);
+
+        let highlife = game.with_rule(Rule::parse("B36/S23").unwrap());
+        assert!(highlife.next_state().cells()[1][1]);
+    }
+
+    #[test]
+    fn test_from_str_runs_other_rules() {
+        assert_eq!("B3/S23".parse(), Ok(Rule::conway()));
+        assert_eq!("B3/S2a".parse::<Rule>(), Err(RuleError::Malformed));
+
+        // a seeds cell never survives, so a block explodes
+        let block = GameOfLife::from_lines(&["    ", " •• ", " •• ", "    "]);
+        let seeds = block.clone().with_rule("B2/S".parse().unwrap());
+        assert_eq!(seeds.next_state().population(), 8);
+
+        // life without death never loses a cell
+        let life_without_death = block.with_rule("B3/S012345678".parse().unwrap());
+        let next = life_without_death.next_state();
+        assert!(life_without_death
+            .live_cells()
+            .iter()
+            .all(|&(row, col)| next.cells()[row][col]));
+
+        // day & night treats live and dead cells alike, so an all-live board stays alive
+        let day_and_night = GameOfLife::from_lines(&["•••"; 3])
+            .with_rule("B3678/S34678".parse().unwrap())
+            .with_boundary(crate::BoundaryMode::Toroidal);
+        assert_eq!(day_and_night.next_state(), day_and_night);
+    }
+}
@@ -0,0 +1,206 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::GameOfLife;
+
+const WORD_BITS: usize = 64;
+
+/// A board together with a bounded history of its earlier generations, so that it can be
+/// stepped back as well as forward.
+///
+/// Life can't be run backwards, so each generation is remembered as it is left behind, packed
+/// to one bit per cell. Once the history outgrows its memory budget the oldest generations are
+/// forgotten, and the simulation can only go back as far as
+/// [`earliest_generation`](Simulation::earliest_generation).
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    board: GameOfLife,
+    generation: usize,
+    /// Earlier generations, oldest first, ending with the one just before `board`.
+    history: VecDeque<Snapshot>,
+    history_bytes: usize,
+    memory_budget: usize,
+}
+
+/// A generation's cells, packed row-major into words with cell `i` in bit `i % 64` of word
+/// `i / 64`.
+#[derive(Debug, Clone)]
+struct Snapshot {
+    rows: usize,
+    cols: usize,
+    words: Vec<u64>,
+}
+
+impl Snapshot {
+    fn pack(board: &GameOfLife) -> Snapshot {
+        let (rows, cols) = board.dimensions();
+        let mut words = vec![0; (rows * cols).div_ceil(WORD_BITS)];
+        for (index, &alive) in board.cells().iter().flatten().enumerate() {
+            words[index / WORD_BITS] |= (alive as u64) << (index % WORD_BITS);
+        }
+        Snapshot { rows, cols, words }
+    }
+
+    fn unpack(&self) -> Vec<Vec<bool>> {
+        (0..self.rows)
+            .map(|row| {
+                (row * self.cols..(row + 1) * self.cols)
+                    .map(|index| self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1)
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn bytes(&self) -> usize {
+        self.words.len() * mem::size_of::<u64>()
+    }
+}
+
+impl Simulation {
+    /// Starts a simulation at generation 0 on `board`, keeping at most `memory_budget` bytes of
+    /// packed earlier generations.
+    ///
+    /// Each remembered generation takes `rows * cols / 8` bytes, rounded up to a multiple of 8.
+    /// A budget too small for even one generation keeps no history at all.
+    pub fn new(board: GameOfLife, memory_budget: usize) -> Simulation {
+        Simulation {
+            board,
+            generation: 0,
+            history: VecDeque::new(),
+            history_bytes: 0,
+            memory_budget,
+        }
+    }
+
+    /// The board at the current generation.
+    pub fn board(&self) -> &GameOfLife {
+        &self.board
+    }
+
+    /// The number of generations the board is past the one the simulation started with.
+    pub fn current_generation(&self) -> usize {
+        self.generation
+    }
+
+    /// The oldest generation still remembered, and so the furthest back the simulation can go.
+    pub fn earliest_generation(&self) -> usize {
+        self.generation - self.history.len()
+    }
+
+    /// Advances the board one generation, remembering the one it leaves behind.
+    pub fn step_forward(&mut self) {
+        let snapshot = Snapshot::pack(&self.board);
+        self.history_bytes += snapshot.bytes();
+        self.history.push_back(snapshot);
+        while self.history_bytes > self.memory_budget {
+            let Some(oldest) = self.history.pop_front() else {
+                break;
+            };
+            self.history_bytes -= oldest.bytes();
+        }
+        self.board.step_mut();
+        self.generation += 1;
+    }
+
+    /// Returns the board to the previous generation, or returns `false` and leaves it unchanged
+    /// if that generation has been forgotten.
+    pub fn step_back(&mut self) -> bool {
+        match self.history.pop_back() {
+            Some(snapshot) => {
+                self.history_bytes -= snapshot.bytes();
+                // keep the board's settings and its scratch buffers for stepping forward again
+                self.board.cells = snapshot.unpack();
+                self.generation -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the board to `generation`, stepping forward or back as needed.
+    ///
+    /// Returns `false`, leaving the board unchanged, if `generation` is older than
+    /// [`earliest_generation`](Simulation::earliest_generation).
+    pub fn rewind_to(&mut self, generation: usize) -> bool {
+        if generation < self.earliest_generation() {
+            return false;
+        }
+        while self.generation < generation {
+            self.step_forward();
+        }
+        if self.generation > generation {
+            // only the oldest of the generations popped is restored
+            let newer = self
+                .history
+                .split_off(generation - self.earliest_generation());
+            let snapshot = &newer[0];
+            self.history_bytes -= newer.iter().map(Snapshot::bytes).sum::<usize>();
+            self.board.cells = snapshot.unpack();
+            self.generation = generation;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns;
+
+    #[test]
+    fn test_step_back_retraces_steps() {
+        let glider = patterns::glider().padded(3);
+        let mut simulation = Simulation::new(glider.clone(), usize::MAX);
+        for _ in 0..10 {
+            simulation.step_forward();
+        }
+        assert_eq!(simulation.current_generation(), 10);
+        assert_eq!(*simulation.board(), glider.nth_generation(10));
+        for generation in (0..10).rev() {
+            assert!(simulation.step_back());
+            assert_eq!(simulation.current_generation(), generation);
+            assert_eq!(*simulation.board(), glider.nth_generation(generation));
+        }
+        assert!(!simulation.step_back());
+        assert_eq!(*simulation.board(), glider);
+    }
+
+    #[test]
+    fn test_memory_budget_bounds_history() {
+        // 81 cells pack into two words, so 48 bytes hold three generations
+        let glider = patterns::glider().padded(3);
+        let mut simulation = Simulation::new(glider.clone(), 48);
+        for _ in 0..10 {
+            simulation.step_forward();
+        }
+        assert_eq!(simulation.earliest_generation(), 7);
+        assert!(!simulation.rewind_to(6));
+        assert_eq!(simulation.current_generation(), 10);
+
+        assert!(simulation.rewind_to(7));
+        assert_eq!(*simulation.board(), glider.nth_generation(7));
+        assert_eq!(simulation.earliest_generation(), 7);
+        assert!(!simulation.step_back());
+
+        assert!(simulation.rewind_to(12));
+        assert_eq!(*simulation.board(), glider.nth_generation(12));
+        assert!(simulation.rewind_to(11));
+        assert_eq!(*simulation.board(), glider.nth_generation(11));
+
+        let mut forgetful = Simulation::new(glider, 8);
+        forgetful.step_forward();
+        assert!(!forgetful.step_back());
+    }
+
+    #[test]
+    fn test_step_back_keeps_settings_and_growth() {
+        let growing = patterns::glider().with_auto_grow(true);
+        let mut simulation = Simulation::new(growing.clone(), 1024);
+        simulation.step_forward();
+        assert!(simulation.board().dimensions() > growing.dimensions());
+        assert!(simulation.rewind_to(0));
+        assert_eq!(*simulation.board(), growing);
+    }
+}
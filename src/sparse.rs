@@ -0,0 +1,235 @@
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+
+use crate::{B0RuleError, GameOfLife, Rule};
+
+/// An unbounded board that stores only the coordinates of its live cells.
+///
+/// Coordinates are `(row, col)` and may be negative, so patterns can grow or travel without ever
+/// reaching an edge. Each generation only examines live cells and their neighbors.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SparseLife {
+    live: HashSet<(i64, i64)>,
+    rule: Rule,
+}
+
+impl SparseLife {
+    /// Creates an empty board that evolves under Conway's rule.
+    pub fn new() -> SparseLife {
+        SparseLife::default()
+    }
+
+    /// Returns this board evolving under `rule` instead of its current rule.
+    ///
+    /// Rules with births on 0 neighbors are an error: this board stores live cells only, so it
+    /// has no way to represent the whole plane being born at once.
+    pub fn with_rule(self, rule: Rule) -> Result<SparseLife, B0RuleError> {
+        B0RuleError::check(rule)?;
+        Ok(SparseLife { rule, ..self })
+    }
+
+    /// The rule this board evolves under.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Whether the cell at `row`, `col` is alive.
+    pub fn is_alive(&self, row: i64, col: i64) -> bool {
+        self.live.contains(&(row, col))
+    }
+
+    /// Sets whether the cell at `row`, `col` is alive.
+    pub fn set(&mut self, row: i64, col: i64, alive: bool) {
+        if alive {
+            self.live.insert((row, col));
+        } else {
+            self.live.remove(&(row, col));
+        }
+    }
+
+    /// The number of living cells.
+    pub fn population(&self) -> usize {
+        self.live.len()
+    }
+
+    /// The `(row, col)` coordinates of every live cell, in no particular order.
+    pub fn live_cells(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.live.iter().copied()
+    }
+
+    /// Computes the following generation, leaving this board unchanged.
+    pub fn next_state(&self) -> SparseLife {
+        // only live cells and their neighbors can have a nonzero count, so nothing else can change
+        let mut living_neighbor_counts: HashMap<(i64, i64), usize> = HashMap::new();
+        for &(row, col) in &self.live {
+            for neighbor_row in row - 1..=row + 1 {
+                for neighbor_col in col - 1..=col + 1 {
+                    if (neighbor_row, neighbor_col) != (row, col) {
+                        *living_neighbor_counts
+                            .entry((neighbor_row, neighbor_col))
+                            .or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+        // live cells with no living neighbors never get a count
+        let isolated: Vec<(i64, i64)> = self
+            .live
+            .iter()
+            .copied()
+            .filter(|cell| !living_neighbor_counts.contains_key(cell))
+            .collect();
+        let live = living_neighbor_counts
+            .into_iter()
+            .chain(isolated.into_iter().map(|cell| (cell, 0)))
+            .filter(|&(cell, count)| self.rule.is_alive_next(self.live.contains(&cell), count))
+            .map(|(cell, _)| cell)
+            .collect();
+        SparseLife {
+            live,
+            rule: self.rule,
+        }
+    }
+
+    /// The smallest rectangle containing every live cell, as inclusive
+    /// `(min_row, min_col, max_row, max_col)`, or `None` if no cell is alive.
+    pub fn bounding_box(&self) -> Option<(i64, i64, i64, i64)> {
+        self.live.iter().fold(None, |bounds, &(row, col)| {
+            let (min_row, min_col, max_row, max_col) = bounds.unwrap_or((row, col, row, col));
+            Some((
+                min_row.min(row),
+                min_col.min(col),
+                max_row.max(row),
+                max_col.max(col),
+            ))
+        })
+    }
+
+    /// Copies the cells with rows in `0..rows` and columns in `0..cols` onto a dense board of
+    /// `bounds`, given as `(rows, cols)`; live cells outside it are dropped.
+    pub fn to_dense(&self, bounds: (usize, usize)) -> GameOfLife {
+        self.window((0, 0), bounds)
+    }
+
+    /// Copies the rectangle of `bounds` cells whose top left corner is at `top_left`, both given
+    /// as `(row, col)`, onto a dense board, so that `top_left` becomes row and column 0.
+    pub fn window(&self, top_left: (i64, i64), bounds: (usize, usize)) -> GameOfLife {
+        let (rows, cols) = bounds;
+        let mut game = GameOfLife::dead(rows, cols).with_rule(self.rule);
+        for &(row, col) in &self.live {
+            let offset = |coord: i64, corner: i64| {
+                coord
+                    .checked_sub(corner)
+                    .and_then(|offset| usize::try_from(offset).ok())
+            };
+            if let (Some(row), Some(col)) = (offset(row, top_left.0), offset(col, top_left.1)) {
+                // cells beyond the bounds are dropped
+                let _ = game.set(row, col, true);
+            }
+        }
+        game
+    }
+}
+
+/// Copies the live cells and rule of a dense board, with its top left cell at the origin.
+///
+/// Fails for the same rules as [`SparseLife::with_rule`].
+impl TryFrom<&GameOfLife> for SparseLife {
+    type Error = B0RuleError;
+
+    fn try_from(game: &GameOfLife) -> Result<SparseLife, B0RuleError> {
+        let rule = game.rule();
+        B0RuleError::check(rule)?;
+        let live = game
+            .live_cells()
+            .into_iter()
+            .map(|(row, col)| (row as i64, col as i64))
+            .collect();
+        Ok(SparseLife { live, rule })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glider_travels_indefinitely() {
+        #[rustfmt::skip]
+        let glider = SparseLife::try_from(&GameOfLife::from_lines(&[
+            " • ",
+            "  •",
+            "•••",
+        ]))
+        .unwrap();
+        let mut game = glider.clone();
+        for period in 1..=50 {
+            for _ in 0..4 {
+                game = game.next_state();
+            }
+            let mut expected = SparseLife::new();
+            for (row, col) in glider.live_cells() {
+                expected.set(row + period, col + period, true);
+            }
+            assert_eq!(game, expected);
+        }
+    }
+
+    #[test]
+    fn test_to_dense_matches_dense_engine() {
+        #[rustfmt::skip]
+        let blinker = GameOfLife::from_lines(&[
+            "     ",
+            "  •  ",
+            "  •  ",
+            "  •  ",
+            "     ",
+        ]);
+        let sparse = SparseLife::try_from(&blinker).unwrap();
+        assert_eq!(sparse.to_dense((5, 5)), blinker);
+        assert_eq!(sparse.next_state().to_dense((5, 5)), blinker.next_state());
+    }
+
+    #[test]
+    fn test_window_follows_a_glider() {
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            " • ",
+            "  •",
+            "•••",
+        ]);
+        let mut game = SparseLife::try_from(&glider).unwrap();
+        assert_eq!(SparseLife::new().bounding_box(), None);
+        assert_eq!(game.bounding_box(), Some((0, 0, 2, 2)));
+        for _ in 0..400 {
+            game = game.next_state();
+        }
+        assert_eq!(game.bounding_box(), Some((100, 100, 102, 102)));
+        assert_eq!(game.window((100, 100), (3, 3)), glider);
+        assert_eq!(game.to_dense((100, 100)), GameOfLife::dead(100, 100));
+
+        game.set(-5, -7, true);
+        assert_eq!(game.bounding_box(), Some((-5, -7, 102, 102)));
+        assert_eq!(
+            game.window((-6, -8), (2, 3)),
+            GameOfLife::from_lines(&["   ", " • "])
+        );
+    }
+
+    #[test]
+    fn test_rejects_b0_rules() {
+        let b0 = Rule::parse("B03/S23").unwrap();
+        let error = Err(B0RuleError { rule: b0 });
+        assert_eq!(SparseLife::new().with_rule(b0), error);
+        let dense = GameOfLife::from_lines(&["•"]).with_rule(b0);
+        assert_eq!(SparseLife::try_from(&dense), error);
+
+        let highlife = Rule::parse("B36/S23").unwrap();
+        let sparse = SparseLife::try_from(&dense.with_rule(highlife)).unwrap();
+        assert_eq!(sparse.rule(), highlife);
+        assert_eq!(
+            SparseLife::new().with_rule(highlife).unwrap().rule(),
+            highlife
+        );
+    }
+}
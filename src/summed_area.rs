@@ -0,0 +1,96 @@
+use alloc::vec::Vec;
+
+use crate::{GameOfLife, Neighborhood};
+
+/// A summed-area table of a board, from which any cell's living neighbor count takes constant
+/// time to look up.
+///
+/// The board is extended by a ring as wide as its neighborhood radius, holding whatever its
+/// boundary mode places past each edge, so the neighbors of every cell lie within the table.
+#[derive(Default)]
+pub(crate) struct SummedArea {
+    /// `sums[row][col]` counts the live cells of the extended board above and left of `row`, `col`.
+    sums: Vec<Vec<usize>>,
+    /// The width of the ring around the board.
+    radius: usize,
+}
+
+impl SummedArea {
+    /// Rebuilds the table for `game`, reusing its existing storage.
+    pub(crate) fn build(&mut self, game: &GameOfLife) {
+        let (rows, cols) = game.dimensions();
+        let radius = game.radius();
+        self.radius = radius;
+        self.sums.resize_with(rows + 2 * radius + 1, Vec::new);
+        for sums_row in &mut self.sums {
+            sums_row.clear();
+            sums_row.resize(cols + 2 * radius + 1, 0);
+        }
+        if rows == 0 || cols == 0 {
+            return;
+        }
+        let offset = |extended: usize| extended as isize - radius as isize;
+        for extended_row in 0..rows + 2 * radius {
+            let mut row_sum = 0;
+            for extended_col in 0..cols + 2 * radius {
+                let cell = game.boundary().resolve(
+                    (0, 0),
+                    (offset(extended_row), offset(extended_col)),
+                    (rows, cols),
+                );
+                if let Some((row_num, col_num)) = cell {
+                    row_sum += game.cells()[row_num][col_num] as usize;
+                }
+                let above = self.sums[extended_row][extended_col + 1];
+                self.sums[extended_row + 1][extended_col + 1] = above + row_sum;
+            }
+        }
+    }
+
+    /// Counts the living neighbors in `neighborhood` of the cell at `row_num`, `col_num`, whose
+    /// own state is `alive`.
+    pub(crate) fn count(
+        &self,
+        neighborhood: Neighborhood,
+        row_num: usize,
+        col_num: usize,
+        alive: bool,
+    ) -> usize {
+        // the cell sits at (row_num + radius, col_num + radius) in the extended board
+        let radius = self.radius;
+        let (row, col) = (row_num + radius, col_num + radius);
+        let alive = alive as usize;
+        match neighborhood {
+            Neighborhood::Moore => {
+                self.sum(row - radius, col - radius, row + radius, col + radius) - alive
+            }
+            Neighborhood::VonNeumann => {
+                // one run of cells from each row of the diamond
+                let runs: usize = (row - radius..=row + radius)
+                    .map(|diamond_row| {
+                        let reach = radius - (diamond_row as isize - row as isize).unsigned_abs();
+                        self.sum(diamond_row, col - reach, diamond_row, col + reach)
+                    })
+                    .sum();
+                runs - alive
+            }
+        }
+    }
+
+    /// Whether the row `row_num` and the rows within the radius either side of it, including any
+    /// cells the boundary places past the edges, are all dead.
+    pub(crate) fn is_quiet(&self, row_num: usize) -> bool {
+        // with no columns, and no radius to add any, there are no cells to be alive
+        let Some(last_col) = self.sums[0].len().checked_sub(2) else {
+            return true;
+        };
+        self.sum(row_num, 0, row_num + 2 * self.radius, last_col) == 0
+    }
+
+    /// Counts the live cells of the extended board in an inclusive rectangle.
+    fn sum(&self, top: usize, left: usize, bottom: usize, right: usize) -> usize {
+        self.sums[bottom + 1][right + 1] + self.sums[top][left]
+            - self.sums[top][right + 1]
+            - self.sums[bottom + 1][left]
+    }
+}
@@ -0,0 +1,150 @@
+use core::ops::{BitAnd, BitOr};
+
+use crate::GameOfLife;
+
+/// A set of the rotations and reflections that map a pattern onto itself, as found by
+/// [`GameOfLife::symmetries`].
+///
+/// Sets combine with `|` and intersect with `&`, and [`contains`](Symmetry::contains) checks for
+/// every symmetry in another set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Symmetry {
+    bits: u8,
+}
+
+impl Symmetry {
+    /// Mirroring left to right, as [`GameOfLife::flip_horizontal`] does.
+    pub const FLIP_HORIZONTAL: Symmetry = Symmetry { bits: 1 };
+    /// Mirroring top to bottom, as [`GameOfLife::flip_vertical`] does.
+    pub const FLIP_VERTICAL: Symmetry = Symmetry { bits: 1 << 1 };
+    /// Rotating a quarter turn, in either direction.
+    pub const ROTATE_90: Symmetry = Symmetry { bits: 1 << 2 };
+    /// Rotating a half turn.
+    pub const ROTATE_180: Symmetry = Symmetry { bits: 1 << 3 };
+    /// Reflecting across the diagonal from top left to bottom right, as
+    /// [`GameOfLife::transpose`] does.
+    pub const DIAGONAL: Symmetry = Symmetry { bits: 1 << 4 };
+    /// Reflecting across the diagonal from top right to bottom left.
+    pub const ANTI_DIAGONAL: Symmetry = Symmetry { bits: 1 << 5 };
+
+    /// The set with no symmetries.
+    pub const fn empty() -> Symmetry {
+        Symmetry { bits: 0 }
+    }
+
+    /// The set with every symmetry, which only a pattern as symmetric as a square has.
+    pub const fn all() -> Symmetry {
+        Symmetry { bits: (1 << 6) - 1 }
+    }
+
+    /// The symmetries as a bit set, in the order of the constants above starting from the
+    /// lowest bit.
+    pub const fn bits(self) -> u8 {
+        self.bits
+    }
+
+    /// Whether the set holds no symmetries.
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Whether every symmetry in `other` is also in this set.
+    pub const fn contains(self, other: Symmetry) -> bool {
+        self.bits & other.bits == other.bits
+    }
+}
+
+impl BitOr for Symmetry {
+    type Output = Symmetry;
+
+    fn bitor(self, other: Symmetry) -> Symmetry {
+        Symmetry {
+            bits: self.bits | other.bits,
+        }
+    }
+}
+
+impl BitAnd for Symmetry {
+    type Output = Symmetry;
+
+    fn bitand(self, other: Symmetry) -> Symmetry {
+        Symmetry {
+            bits: self.bits & other.bits,
+        }
+    }
+}
+
+impl GameOfLife {
+    /// The rotations and reflections that leave this board's pattern, trimmed to its live cells,
+    /// unchanged.
+    ///
+    /// A board with no live cells has every symmetry.
+    pub fn symmetries(&self) -> Symmetry {
+        let trimmed = self.trimmed();
+        let half_turn = trimmed.rotate_cw().rotate_cw();
+        [
+            (Symmetry::FLIP_HORIZONTAL, trimmed.flip_horizontal()),
+            (Symmetry::FLIP_VERTICAL, trimmed.flip_vertical()),
+            (Symmetry::ROTATE_90, trimmed.rotate_cw()),
+            (Symmetry::DIAGONAL, trimmed.transpose()),
+            (Symmetry::ANTI_DIAGONAL, half_turn.transpose()),
+            (Symmetry::ROTATE_180, half_turn),
+        ]
+        .iter()
+        .filter(|(_, transformed)| transformed.cells() == trimmed.cells())
+        .fold(Symmetry::empty(), |symmetries, &(symmetry, _)| {
+            symmetries | symmetry
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns;
+
+    #[test]
+    fn test_symmetries_of_still_lifes() {
+        assert_eq!(patterns::block().symmetries(), Symmetry::all());
+        assert_eq!(patterns::tub().padded(2).symmetries(), Symmetry::all());
+        assert_eq!(
+            patterns::beehive().symmetries(),
+            Symmetry::FLIP_HORIZONTAL | Symmetry::FLIP_VERTICAL | Symmetry::ROTATE_180
+        );
+        assert_eq!(
+            patterns::beehive().rotate_cw().symmetries(),
+            patterns::beehive().symmetries()
+        );
+    }
+
+    #[test]
+    fn test_symmetries_of_glider() {
+        let glider = patterns::glider();
+        // a glider's phases are each other's reflections, but none is its own
+        for phase in glider.padded(1).generations().take(4) {
+            assert!(phase.symmetries().is_empty());
+        }
+
+        let corner = GameOfLife::from_lines(&["•• ", "•  ", "   "]);
+        assert_eq!(corner.symmetries(), Symmetry::DIAGONAL);
+        assert_eq!(
+            corner.flip_horizontal().symmetries(),
+            Symmetry::ANTI_DIAGONAL
+        );
+    }
+
+    #[test]
+    fn test_symmetry_set_operations() {
+        let mirrors = Symmetry::FLIP_HORIZONTAL | Symmetry::FLIP_VERTICAL;
+        assert!(mirrors.contains(Symmetry::FLIP_VERTICAL));
+        assert!(!mirrors.contains(Symmetry::FLIP_VERTICAL | Symmetry::ROTATE_90));
+        assert_eq!(mirrors & Symmetry::FLIP_VERTICAL, Symmetry::FLIP_VERTICAL);
+        assert_eq!(mirrors.bits(), 0b11);
+        assert!(Symmetry::all().contains(mirrors));
+        assert_eq!(Symmetry::default(), Symmetry::empty());
+        assert_eq!(
+            GameOfLife::from_lines(&["  "; 2]).symmetries(),
+            Symmetry::all()
+        );
+    }
+}
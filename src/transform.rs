@@ -0,0 +1,552 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{DimensionsError, GameOfLife, ShapeError};
+
+impl GameOfLife {
+    /// A copy of this board cropped to the bounding box of its live cells, or a 0x0 board if no
+    /// cell is alive.
+    pub fn trimmed(&self) -> GameOfLife {
+        let cells = match self.bounding_box() {
+            Some((min_row, min_col, max_row, max_col)) => self.cells()[min_row..=max_row]
+                .iter()
+                .map(|row| row[min_col..=max_col].to_vec())
+                .collect(),
+            None => Vec::new(),
+        };
+        self.with_cells(cells)
+    }
+
+    /// This board's pattern, trimmed to its live cells, placed as near the middle of a blank
+    /// `rows` by `cols` board as possible.
+    ///
+    /// If the pattern doesn't fit, the error gives the trimmed pattern's dimensions along with
+    /// `rows` and `cols`.
+    pub fn centered_on(&self, rows: usize, cols: usize) -> Result<GameOfLife, DimensionsError> {
+        let pattern = self.trimmed();
+        let (pattern_rows, pattern_cols) = pattern.dimensions();
+        if pattern_rows > rows || pattern_cols > cols {
+            return Err(DimensionsError {
+                found: (pattern_rows, pattern_cols),
+                expected: (rows, cols),
+            });
+        }
+        let mut centered = self.with_cells(vec![vec![false; cols]; rows]);
+        centered.overlay(
+            &pattern,
+            (rows - pattern_rows) / 2,
+            (cols - pattern_cols) / 2,
+        );
+        Ok(centered)
+    }
+
+    /// A copy of the `height` by `width` window of this board whose top-left cell is at `top`,
+    /// `left`.
+    ///
+    /// The window is clipped to the board, so a window lying entirely outside it gives a 0x0
+    /// board.
+    pub fn subgrid(&self, top: usize, left: usize, height: usize, width: usize) -> GameOfLife {
+        let (rows, cols) = self.dimensions();
+        let height = height.min(rows.saturating_sub(top));
+        let width = width.min(cols.saturating_sub(left));
+        if height == 0 || width == 0 {
+            return self.with_cells(Vec::new());
+        }
+        self.with_cells(
+            self.cells()[top..top + height]
+                .iter()
+                .map(|row| row[left..left + width].to_vec())
+                .collect(),
+        )
+    }
+
+    /// A copy of this board with `margin` rows and columns of dead cells added on every side.
+    pub fn padded(&self, margin: usize) -> GameOfLife {
+        let (_, cols) = self.dimensions();
+        self.with_cells(padded_grid(self.cells(), cols, margin, false))
+    }
+
+    /// This board repeated `rows_repeat` times downwards and `cols_repeat` times across.
+    pub fn tile(&self, rows_repeat: usize, cols_repeat: usize) -> GameOfLife {
+        self.with_cells(
+            (0..rows_repeat)
+                .flat_map(|_| self.cells().iter().map(|row| row.repeat(cols_repeat)))
+                .collect(),
+        )
+    }
+
+    /// This board with `other` placed to its right.
+    ///
+    /// The boards must have the same number of rows, unless one of them is empty, in which case
+    /// the other is returned. Otherwise the error describes the first row of the combined board
+    /// that only one of them would fill.
+    pub fn hstack(&self, other: &GameOfLife) -> Result<GameOfLife, ShapeError> {
+        let ((rows, cols), (other_rows, other_cols)) = (self.dimensions(), other.dimensions());
+        if rows == 0 || other_rows == 0 {
+            return Ok(self.with_cells(max_by_rows(self, other).cells().clone()));
+        }
+        if rows != other_rows {
+            return Err(ShapeError {
+                row: rows.min(other_rows),
+                len: max_by_rows(self, other).dimensions().1,
+                expected: cols + other_cols,
+            });
+        }
+        let cells = self.cells().iter().zip(other.cells());
+        Ok(self.with_cells(
+            cells
+                .map(|(row, other_row)| [&row[..], other_row].concat())
+                .collect(),
+        ))
+    }
+
+    /// This board with `other` placed below it.
+    ///
+    /// The boards must have the same number of columns, unless one of them is empty, in which
+    /// case the other is returned.
+    pub fn vstack(&self, other: &GameOfLife) -> Result<GameOfLife, ShapeError> {
+        let ((rows, cols), (other_rows, other_cols)) = (self.dimensions(), other.dimensions());
+        if rows == 0 || other_rows == 0 {
+            return Ok(self.with_cells(max_by_rows(self, other).cells().clone()));
+        }
+        if cols != other_cols {
+            return Err(ShapeError {
+                row: rows,
+                len: other_cols,
+                expected: cols,
+            });
+        }
+        Ok(self.with_cells([&self.cells()[..], other.cells()].concat()))
+    }
+
+    /// A copy of this board rotated 90° clockwise, so an RxC board becomes CxR.
+    pub fn rotate_cw(&self) -> GameOfLife {
+        let (rows, cols) = self.dimensions();
+        self.remapped(cols, rows, |row, col| (rows - 1 - col, row))
+    }
+
+    /// A copy of this board rotated 90° counterclockwise, so an RxC board becomes CxR.
+    pub fn rotate_ccw(&self) -> GameOfLife {
+        let (rows, cols) = self.dimensions();
+        self.remapped(cols, rows, |row, col| (col, cols - 1 - row))
+    }
+
+    /// A copy of this board with rows and columns swapped, so the cell at `(row, col)` moves to
+    /// `(col, row)`.
+    pub fn transpose(&self) -> GameOfLife {
+        let (rows, cols) = self.dimensions();
+        self.remapped(cols, rows, |row, col| (col, row))
+    }
+
+    /// A copy of this board mirrored left to right, reversing each row.
+    pub fn flip_horizontal(&self) -> GameOfLife {
+        self.with_cells(
+            self.cells()
+                .iter()
+                .map(|row| row.iter().rev().copied().collect())
+                .collect(),
+        )
+    }
+
+    /// A copy of this board mirrored top to bottom, reversing the order of the rows.
+    pub fn flip_vertical(&self) -> GameOfLife {
+        self.with_cells(self.cells().iter().rev().cloned().collect())
+    }
+
+    /// A representative of this board's pattern that ignores where it lies and how it is turned.
+    ///
+    /// The board is trimmed to its live cells, and the lexicographically smallest of the 4
+    /// rotations of it and of its mirror image is chosen, so two boards hold the same pattern
+    /// exactly when their canonical forms are equal.
+    pub fn canonical(&self) -> GameOfLife {
+        let trimmed = self.trimmed();
+        let mirrored = trimmed.flip_horizontal();
+        [trimmed, mirrored]
+            .iter()
+            .flat_map(|game| {
+                let quarter = game.rotate_cw();
+                let half = quarter.rotate_cw();
+                let three_quarters = half.rotate_cw();
+                vec![game.clone(), quarter, half, three_quarters]
+            })
+            .min_by(|a, b| a.cells().cmp(b.cells()))
+            .expect("there are always 8 orientations")
+    }
+
+    /// A hash of this board's [`canonical`](GameOfLife::canonical) form, which is the same in
+    /// every run and on every platform, so it can key a database of patterns.
+    ///
+    /// Boards with different hashes hold different patterns, and boards with equal hashes almost
+    /// certainly hold the same one, up to position, rotation and reflection.
+    pub fn shape_hash(&self) -> u64 {
+        // 64 bit FNV-1a, over the dimensions and then every cell
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+        let canonical = self.canonical();
+        let (rows, cols) = canonical.dimensions();
+        let dimensions = [rows as u64, cols as u64];
+        let bytes = dimensions
+            .iter()
+            .flat_map(|dimension| dimension.to_le_bytes());
+        let cells = canonical.cells().iter().flatten().map(|&alive| alive as u8);
+        bytes.chain(cells).fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(PRIME)
+        })
+    }
+
+    /// Whether the two boards hold the same pattern in the same orientation, wherever it lies on
+    /// each of them.
+    ///
+    /// Only the cells are compared, not the boards' rules or other settings. Use
+    /// [`canonical`](GameOfLife::canonical) to also ignore rotations and reflections.
+    pub fn same_shape(&self, other: &GameOfLife) -> bool {
+        self.trimmed().cells() == other.trimmed().cells()
+    }
+
+    /// Builds a `rows` by `cols` board where each cell copies the cell of this board at the
+    /// position `source` maps it to.
+    fn remapped(
+        &self,
+        rows: usize,
+        cols: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> GameOfLife {
+        self.with_cells(
+            (0..rows)
+                .map(|row| {
+                    (0..cols)
+                        .map(|col| {
+                            let (source_row, source_col) = source(row, col);
+                            self.cells()[source_row][source_col]
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Whichever of two boards has more rows, preferring `a`.
+fn max_by_rows<'a>(a: &'a GameOfLife, b: &'a GameOfLife) -> &'a GameOfLife {
+    if b.dimensions().0 > a.dimensions().0 {
+        b
+    } else {
+        a
+    }
+}
+
+/// `grid`, whose rows are `cols` wide, with `margin` rows and columns of `fill` added on every
+/// side, as [`GameOfLife::padded`] pads a board's cells.
+///
+/// Grids that line up with a board's cells are padded this way to stay lined up with it once it
+/// [grows](GameOfLife::grow).
+pub(crate) fn padded_grid<T: Clone>(
+    grid: &[Vec<T>],
+    cols: usize,
+    margin: usize,
+    fill: T,
+) -> Vec<Vec<T>> {
+    let padded_cols = cols + 2 * margin;
+    let mut padded = vec![vec![fill.clone(); padded_cols]; margin];
+    padded.extend(grid.iter().map(|row| {
+        let mut padded_row = vec![fill.clone(); margin];
+        padded_row.extend_from_slice(row);
+        padded_row.resize(padded_cols, fill.clone());
+        padded_row
+    }));
+    padded.extend((0..margin).map(|_| vec![fill.clone(); padded_cols]));
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DimensionsError, GameOfLife, ShapeError};
+
+    #[test]
+    fn test_trimmed() {
+        #[rustfmt::skip]
+        let padded_block = GameOfLife::from_lines(&[
+            "    ",
+            " •• ",
+            " •• ",
+            "    ",
+        ]);
+        assert_eq!(
+            padded_block.trimmed(),
+            GameOfLife::from_lines(&["••", "••"])
+        );
+        assert_eq!(
+            GameOfLife::from_lines(&["  ", "  "]).trimmed().dimensions(),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn test_padded_blinker_evolves() {
+        let blinker = GameOfLife::from_lines(&["•••"]);
+        assert_eq!(blinker.next_state(), GameOfLife::from_lines(&[" • "]));
+
+        let padded = blinker.padded(1);
+        #[rustfmt::skip]
+        assert_eq!(padded, GameOfLife::from_lines(&[
+            "     ",
+            " ••• ",
+            "     ",
+        ]));
+        #[rustfmt::skip]
+        assert_eq!(padded.next_state(), GameOfLife::from_lines(&[
+            "  •  ",
+            "  •  ",
+            "  •  ",
+        ]));
+        assert_eq!(padded.padded(2).trimmed(), blinker);
+    }
+
+    #[test]
+    fn test_rotate() {
+        #[rustfmt::skip]
+        let vertical = GameOfLife::from_lines(&[
+            "•",
+            "•",
+            "•",
+        ]);
+        assert_eq!(vertical.rotate_cw(), GameOfLife::from_lines(&["•••"]));
+
+        #[rustfmt::skip]
+        let l_shape = GameOfLife::from_lines(&[
+            "• ",
+            "• ",
+            "••",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(l_shape.rotate_cw(), GameOfLife::from_lines(&[
+            "•••",
+            "•  ",
+        ]));
+        #[rustfmt::skip]
+        assert_eq!(l_shape.rotate_ccw(), GameOfLife::from_lines(&[
+            "  •",
+            "•••",
+        ]));
+        assert_eq!(l_shape.rotate_cw().rotate_ccw(), l_shape);
+        assert_eq!(GameOfLife::from_lines(&[]).rotate_cw().dimensions(), (0, 0));
+    }
+
+    #[test]
+    fn test_flip() {
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            " • ",
+            "  •",
+            "•••",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(glider.flip_horizontal(), GameOfLife::from_lines(&[
+            " • ",
+            "•  ",
+            "•••",
+        ]));
+        #[rustfmt::skip]
+        assert_eq!(glider.flip_vertical(), GameOfLife::from_lines(&[
+            "•••",
+            "  •",
+            " • ",
+        ]));
+    }
+
+    #[test]
+    fn test_symmetric_still_lifes_are_flip_invariant() {
+        #[rustfmt::skip]
+        let beehive = GameOfLife::from_lines(&[
+            "      ",
+            "  ••  ",
+            " •  • ",
+            "  ••  ",
+            "      ",
+        ]);
+        #[rustfmt::skip]
+        let tub = GameOfLife::from_lines(&[
+            "     ",
+            "  •  ",
+            " • • ",
+            "  •  ",
+            "     ",
+        ]);
+        assert_eq!(beehive.flip_horizontal(), beehive);
+        assert_eq!(beehive.flip_vertical(), beehive);
+        assert_eq!(tub.flip_horizontal(), tub);
+        assert_eq!(tub.flip_vertical(), tub);
+    }
+
+    #[test]
+    fn test_subgrid() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            "•   ",
+            " •• ",
+            " • •",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(game.subgrid(1, 1, 2, 2), GameOfLife::from_lines(&[
+            "••",
+            "• ",
+        ]));
+        #[rustfmt::skip]
+        assert_eq!(game.subgrid(1, 2, 5, 5), GameOfLife::from_lines(&[
+            "• ",
+            " •",
+        ]));
+        assert_eq!(game.subgrid(0, 0, 3, 4), game);
+        assert_eq!(game.subgrid(3, 0, 2, 2).dimensions(), (0, 0));
+        assert_eq!(game.subgrid(0, 4, 2, 2).dimensions(), (0, 0));
+        assert_eq!(game.subgrid(0, 0, 0, 2).dimensions(), (0, 0));
+    }
+
+    #[test]
+    fn test_canonical() {
+        #[rustfmt::skip]
+        let glider = GameOfLife::from_lines(&[
+            "      ",
+            "  •   ",
+            "   •  ",
+            " •••  ",
+        ]);
+        #[rustfmt::skip]
+        let turned_glider = GameOfLife::from_lines(&[
+            "•••",
+            "  •",
+            " • ",
+            "   ",
+        ]);
+        assert_eq!(glider.canonical(), turned_glider.canonical());
+        assert_eq!(glider.canonical(), glider.flip_horizontal().canonical());
+        assert_eq!(glider.canonical().dimensions(), (3, 3));
+
+        let vertical = GameOfLife::from_lines(&["  •", "  •", "  •", "   "]);
+        let horizontal = GameOfLife::from_lines(&["     ", " ••• "]);
+        assert_eq!(vertical.canonical(), horizontal.canonical());
+        assert_ne!(glider.canonical(), horizontal.canonical());
+    }
+
+    #[test]
+    fn test_tile() {
+        let block = GameOfLife::from_lines(&["    ", " •• ", " •• ", "    "]);
+        let tiled = block.tile(3, 2);
+        assert_eq!(tiled.dimensions(), (12, 8));
+        assert_eq!(tiled.population(), 24);
+        assert!(tiled.is_still_life());
+        assert_eq!(tiled.subgrid(8, 4, 4, 4), block);
+        assert_eq!(block.tile(1, 1), block);
+        assert_eq!(block.tile(0, 3).dimensions(), (0, 0));
+    }
+
+    #[test]
+    fn test_hstack() {
+        let left = GameOfLife::from_lines(&["• ", " •"]);
+        let right = GameOfLife::from_lines(&["•••", "   "]);
+        #[rustfmt::skip]
+        assert_eq!(left.hstack(&right), Ok(GameOfLife::from_lines(&[
+            "• •••",
+            " •   ",
+        ])));
+        assert_eq!(left.hstack(&GameOfLife::from_lines(&[])), Ok(left.clone()));
+        assert_eq!(GameOfLife::from_lines(&[]).hstack(&left), Ok(left.clone()));
+        assert_eq!(
+            left.hstack(&GameOfLife::from_lines(&["•"])),
+            Err(ShapeError {
+                row: 1,
+                len: 2,
+                expected: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_vstack() {
+        let top = GameOfLife::from_lines(&["• ", " •"]);
+        let bottom = GameOfLife::from_lines(&["••"]);
+        #[rustfmt::skip]
+        assert_eq!(top.vstack(&bottom), Ok(GameOfLife::from_lines(&[
+            "• ",
+            " •",
+            "••",
+        ])));
+        assert_eq!(GameOfLife::from_lines(&[]).vstack(&top), Ok(top.clone()));
+        assert_eq!(
+            top.vstack(&GameOfLife::from_lines(&["•••"])),
+            Err(ShapeError {
+                row: 2,
+                len: 3,
+                expected: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_transpose() {
+        #[rustfmt::skip]
+        let game = GameOfLife::from_lines(&[
+            "•• ",
+            "  •",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(game.transpose(), GameOfLife::from_lines(&[
+            "• ",
+            "• ",
+            " •",
+        ]));
+        assert_eq!(game.transpose().transpose(), game);
+        assert_eq!(game.transpose(), game.rotate_cw().flip_horizontal());
+    }
+
+    #[test]
+    fn test_centered_on() {
+        let game = GameOfLife::from_lines(&["••   ", "•    ", "     "]);
+        #[rustfmt::skip]
+        assert_eq!(game.centered_on(4, 5), Ok(GameOfLife::from_lines(&[
+            "     ",
+            " ••  ",
+            " •   ",
+            "     ",
+        ])));
+        assert_eq!(game.centered_on(2, 2), Ok(game.trimmed()));
+        assert_eq!(
+            GameOfLife::from_lines(&["   "]).centered_on(2, 2),
+            Ok(GameOfLife::from_lines(&["  ", "  "]))
+        );
+        assert_eq!(
+            game.centered_on(1, 5),
+            Err(DimensionsError {
+                found: (2, 2),
+                expected: (1, 5)
+            })
+        );
+    }
+
+    #[test]
+    fn test_same_shape() {
+        let block = GameOfLife::from_lines(&["••   ", "••   ", "     "]);
+        let other_block = GameOfLife::from_lines(&["    ", "    ", "  ••", "  ••"]);
+        assert!(block.same_shape(&other_block));
+
+        let glider = GameOfLife::from_lines(&[" • ", "  •", "•••"]);
+        assert!(glider.same_shape(&glider.padded(3)));
+        assert!(!glider.same_shape(&glider.rotate_cw()));
+        assert!(!glider.same_shape(&block));
+        assert!(GameOfLife::from_lines(&[" "]).same_shape(&GameOfLife::from_lines(&[])));
+    }
+
+    #[test]
+    fn test_shape_hash() {
+        let glider = GameOfLife::from_lines(&[" • ", "  •", "•••"]);
+        let block = GameOfLife::from_lines(&["••", "••"]);
+        assert_eq!(
+            glider.shape_hash(),
+            glider.rotate_ccw().padded(2).shape_hash()
+        );
+        assert_eq!(glider.shape_hash(), glider.flip_vertical().shape_hash());
+        assert_ne!(glider.shape_hash(), block.shape_hash());
+        // pinned so that the hash can't change between releases unnoticed
+        assert_eq!(block.shape_hash(), 0x3103_d578_7ecc_ea19);
+    }
+}
@@ -0,0 +1,41 @@
+use game_of_life::{patterns, GameOfLife};
+
+/// The Gosper glider gun near the top-left corner of a 50x50 board, leaving room below and to the
+/// right for the gliders it fires.
+fn gun_on_large_board() -> GameOfLife {
+    let mut board = GameOfLife::new(vec![vec![false; 50]; 50]).unwrap();
+    board.overlay(&patterns::gosper_glider_gun(), 1, 1);
+    board
+}
+
+/// The part of the board below and to the right of the gun, which only gliders reach.
+fn glider_region(board: &GameOfLife) -> GameOfLife {
+    board.subgrid(10, 20, 40, 30)
+}
+
+#[test]
+fn test_gun_emits_a_glider() {
+    let board = gun_on_large_board();
+    assert!(glider_region(&board).is_extinct());
+
+    let later = board.nth_generation(30);
+    assert!(later.population() > board.population());
+    // a glider has 5 cells and reappears 4 generations later, moved diagonally by one cell
+    let glider = glider_region(&later).trimmed();
+    assert_eq!(glider.population(), 5);
+    let moved = glider.padded(2).nth_generation(4);
+    assert_ne!(moved, glider.padded(2));
+    assert_eq!(moved.trimmed(), glider);
+    // the gun itself has period 30
+    assert_eq!(later.subgrid(0, 0, 10, 50), board.subgrid(0, 0, 10, 50));
+}
+
+#[test]
+fn test_gun_keeps_firing() {
+    let board = gun_on_large_board();
+    let populations: Vec<usize> = [30, 60, 90]
+        .iter()
+        .map(|&generation| board.nth_generation(generation).population())
+        .collect();
+    assert_eq!(populations, vec![41, 46, 51]);
+}
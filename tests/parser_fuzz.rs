@@ -0,0 +1,129 @@
+//! Feeds the pattern parsers large numbers of random and mangled inputs, checking that each one
+//! either parses or is rejected with an error, without panicking.
+
+use game_of_life::{patterns, GameOfLife, Rng, SplitMix64};
+
+const CASES: usize = 2_000;
+
+/// A random string of up to `max_len` characters from `alphabet`.
+fn random_string(rng: &mut SplitMix64, alphabet: &[char], max_len: usize) -> String {
+    let len = rng.next_u64() as usize % (max_len + 1);
+    (0..len)
+        .map(|_| alphabet[rng.next_u64() as usize % alphabet.len()])
+        .collect()
+}
+
+/// Random bytes, with any invalid UTF-8 replaced.
+fn random_text(rng: &mut SplitMix64, max_len: usize) -> String {
+    let len = rng.next_u64() as usize % (max_len + 1);
+    let bytes: Vec<u8> = (0..len).map(|_| rng.next_u64() as u8).collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// `input` with a few of its characters replaced, removed or duplicated, or cut short.
+fn mangle(rng: &mut SplitMix64, input: &str, alphabet: &[char]) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    for _ in 0..1 + rng.next_u64() % 4 {
+        if chars.is_empty() {
+            break;
+        }
+        let index = rng.next_u64() as usize % chars.len();
+        match rng.next_u64() % 4 {
+            0 => chars[index] = alphabet[rng.next_u64() as usize % alphabet.len()],
+            1 => {
+                chars.remove(index);
+            }
+            2 => chars.insert(index, chars[index]),
+            _ => chars.truncate(index),
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// A successfully parsed board must survive a round trip through the same format.
+fn assert_valid_rle(parsed: Result<GameOfLife, game_of_life::RleError>) {
+    if let Ok(game) = parsed {
+        assert_eq!(GameOfLife::from_rle(&game.to_rle()), Ok(game));
+    }
+}
+
+#[test]
+fn test_from_rle_never_panics() {
+    const RLE_CHARS: &[char] = &[
+        'x', 'y', '=', ',', ' ', '\n', 'b', 'o', '$', '!', '#', '0', '1', '2', '3', '9', 'r', 'u',
+        'l', 'e', 'B', 'S', '/',
+    ];
+    let mut rng = SplitMix64::new(94);
+    let valid = [
+        patterns::glider().to_rle(),
+        patterns::gosper_glider_gun().to_rle(),
+        patterns::beacon().padded(3).to_rle(),
+    ];
+    for case in 0..CASES {
+        assert_valid_rle(GameOfLife::from_rle(&random_text(&mut rng, 64)));
+        let header = format!("x = {}, y = {}\n", rng.next_u64() % 20, rng.next_u64() % 20);
+        let body = random_string(&mut rng, RLE_CHARS, 80);
+        assert_valid_rle(GameOfLife::from_rle(&(header + &body)));
+        let mangled = mangle(&mut rng, &valid[case % valid.len()], RLE_CHARS);
+        assert_valid_rle(GameOfLife::from_rle(&mangled));
+    }
+}
+
+#[test]
+fn test_from_rle_rejects_hostile_headers() {
+    for input in &[
+        "x = 18446744073709551615, y = 18446744073709551615\n!",
+        "x = 4294967296, y = 4294967296\n!",
+        "x = 0, y = 18446744073709551615\n!",
+        "x = 18446744073709551616, y = 1\n!",
+        "x = 3, y = 3\n18446744073709551615$!",
+        "x = 3, y = 3\n99999999999999999999999999b!",
+        "x = 3, y = 3, rule = B99999999999/S\n!",
+        "x = -1, y = 3\n!",
+    ] {
+        assert!(GameOfLife::from_rle(input).is_err(), "accepted {:?}", input);
+    }
+}
+
+#[test]
+fn test_from_rle_handles_zero_run_counts() {
+    // a zero count is a no-op wherever it appears, including once every row is full
+    for body in &[
+        "o$0$o!",
+        "0$o!",
+        "o0$!",
+        "o$0$!",
+        "0o$0b!",
+        "0oo$0bo!",
+        "o$o0o!",
+        "o$o0b!",
+        "o$o0o0$0b!",
+        "2o$0$$o!",
+    ] {
+        for header in &["x = 1, y = 1\n", "x = 2, y = 2\n"] {
+            assert_valid_rle(GameOfLife::from_rle(&format!("{}{}", header, body)));
+        }
+    }
+    assert!(GameOfLife::from_rle("x = 1, y = 1\no$0$o!").is_err());
+    assert!(GameOfLife::from_rle("x = 1, y = 2\no$0$o0o0b0$!").is_ok());
+}
+
+#[test]
+fn test_from_plaintext_never_panics() {
+    const PLAINTEXT_CHARS: &[char] = &['O', '.', '*', '!', ' ', '\n', '\r', 'x', '•'];
+    let mut rng = SplitMix64::new(94);
+    let valid = patterns::gosper_glider_gun().to_plaintext();
+    for _ in 0..CASES {
+        for input in &[
+            random_text(&mut rng, 64),
+            random_string(&mut rng, PLAINTEXT_CHARS, 80),
+            mangle(&mut rng, &valid, PLAINTEXT_CHARS),
+        ] {
+            let game = GameOfLife::from_plaintext(input);
+            let (rows, cols) = game.dimensions();
+            assert!(game.cells().iter().all(|row| row.len() == cols));
+            assert!(rows <= input.lines().count());
+            assert_eq!(GameOfLife::from_plaintext(&game.to_plaintext()), game);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Counts the heap allocations made while stepping boards in place, which should only happen
+//! while the spare buffers are first being filled.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use game_of_life::{patterns, BoundaryMode, GameOfLife, Neighborhood, SplitMix64};
+
+/// The system allocator, counting the allocations made by each thread.
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// The number of allocations `f` makes on this thread.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn test_steady_state_steps_do_not_allocate() {
+    let mut rng = SplitMix64::new(259);
+    let boards = [
+        patterns::gosper_glider_gun().padded(20),
+        GameOfLife::random(64, 48, 0.4, &mut rng).with_boundary(BoundaryMode::Toroidal),
+        GameOfLife::random(30, 30, 0.2, &mut rng)
+            .with_neighborhood(Neighborhood::VonNeumann)
+            .with_radius(2),
+    ];
+    for mut board in boards {
+        let expected = board.nth_generation(101);
+        assert!(allocations(|| board.advance(1)) > 0);
+        assert_eq!(allocations(|| board.advance(100)), 0);
+        assert_eq!(board, expected);
+    }
+}